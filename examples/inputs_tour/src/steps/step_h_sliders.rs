@@ -7,6 +7,8 @@ use iced_audio::{
 
 use crate::{style, Step};
 
+static INT_LABELS: [&str; 6] = ["A", "B", "C", "D", "E", "F"];
+
 #[derive(Debug, Clone)]
 pub enum Message {
     Float(Normal),
@@ -52,7 +54,7 @@ impl Default for HSliderStep {
         // initalize parameters
 
         let float_range = FloatRange::default_bipolar();
-        let int_range = IntRange::new(0, 5);
+        let int_range = IntRange::enumerated(&INT_LABELS);
         let db_range = LogDBRange::default();
         let freq_range = FreqRange::default();
 
@@ -124,35 +126,16 @@ impl Default for HSliderStep {
             ]
             .into(),
 
-            freq_tick_marks: vec![
-                (freq_range.map_to_normal(20.0), tick_marks::Tier::Two),
-                (freq_range.map_to_normal(50.0), tick_marks::Tier::Two),
-                (freq_range.map_to_normal(100.0), tick_marks::Tier::One),
-                (freq_range.map_to_normal(200.0), tick_marks::Tier::Two),
-                (freq_range.map_to_normal(400.0), tick_marks::Tier::Two),
-                (freq_range.map_to_normal(1000.0), tick_marks::Tier::One),
-                (freq_range.map_to_normal(2000.0), tick_marks::Tier::Two),
-                (freq_range.map_to_normal(5000.0), tick_marks::Tier::Two),
-                (freq_range.map_to_normal(10000.0), tick_marks::Tier::One),
-                (freq_range.map_to_normal(20000.0), tick_marks::Tier::Two),
-            ]
-            .into(),
+            freq_tick_marks: freq_range.octave_tick_marks(20.0, 20_000.0),
 
             float_text_marks: text_marks::Group::min_max_and_center(
                 "-1", "+1", "0",
             ),
-            int_text_marks: text_marks::Group::evenly_spaced(&[
-                "A", "B", "C", "D", "E", "F",
-            ]),
+            int_text_marks: IntRange::enumerated_text_marks(&INT_LABELS),
             db_text_marks: text_marks::Group::min_max_and_center(
                 "-12", "+12", "0",
             ),
-            freq_text_marks: vec![
-                (freq_range.map_to_normal(100.0), "100"),
-                (freq_range.map_to_normal(1000.0), "1k"),
-                (freq_range.map_to_normal(10000.0), "10k"),
-            ]
-            .into(),
+            freq_text_marks: freq_range.octave_text_marks(20.0, 20_000.0),
 
             output_text: String::from("Move a widget"),
         }
@@ -135,6 +135,8 @@ impl h_slider::StyleSheet for TextureStyle {
                     width: 1,
                     color: [0.56, 0.56, 0.56, 0.75].into(),
                 },
+                tier_offsets: Default::default(),
+                anti_alias: false,
             },
             placement: tick_marks::Placement::CenterSplit {
                 offset: Offset::ZERO,
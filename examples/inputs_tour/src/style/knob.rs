@@ -19,6 +19,7 @@ impl CustomStyleCircle {
         border_width: 3,
         border_color: colors::KNOB_BORDER,
         notch: knob::NotchShape::Circle(Self::ACTIVE_CIRCLE_NOTCH),
+        rings: Vec::new(),
     };
 }
 impl knob::StyleSheet for CustomStyleCircle {
@@ -48,6 +49,7 @@ impl knob::StyleSheet for CustomStyleCircle {
             empty_color: Some(colors::KNOB_ARC_EMPTY),
             left_filled_color: colors::KNOB_ARC,
             right_filled_color: None,
+            fill_mode: knob::ArcFillMode::FromStart,
             cap: knob::LineCap::Butt,
         })
     }
@@ -95,6 +97,7 @@ impl CustomStyleLine {
         border_width: 0,
         border_color: Color::TRANSPARENT,
         notch: knob::NotchShape::Line(Self::ACTIVE_CIRCLE_NOTCH),
+        rings: Vec::new(),
     };
 }
 impl knob::StyleSheet for CustomStyleLine {
@@ -118,6 +121,7 @@ impl knob::StyleSheet for CustomStyleLine {
             empty_color: Some(colors::KNOB_ARC_EMPTY),
             left_filled_color: colors::KNOB_ARC,
             right_filled_color: Some(colors::KNOB_ARC_RIGHT),
+            fill_mode: knob::ArcFillMode::FromCenter,
             cap: knob::LineCap::Round,
         })
     }
@@ -0,0 +1,171 @@
+//! A bridge between host parameters and widgets
+
+use crate::core::{
+    FloatRange, FreqRange, IntRange, LogDBRange, Normal, NormalParam,
+    NoteDivision, NoteDivisionRange, PanRange,
+};
+
+/// A parameter that can be read and written as a [`Normal`], such as a
+/// parameter exposed to a plugin host.
+///
+/// The `on_change` message of a widget can call [`set_normalized`], and the
+/// value the widget displays can be read from [`normalized`] each frame,
+/// keeping the host and the GUI in sync.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Automatable, FloatRange, Normal, RangedParam};
+///
+/// let mut cutoff = RangedParam::new(FloatRange::new(-12.0, 12.0), 0.0);
+/// assert_eq!(cutoff.normalized().as_f32(), 0.5);
+///
+/// cutoff.set_normalized(Normal::new(0.75));
+/// assert_eq!(cutoff.value, 6.0);
+/// ```
+///
+/// [`Normal`]: ../normal/struct.Normal.html
+/// [`set_normalized`]: trait.Automatable.html#tymethod.set_normalized
+/// [`normalized`]: trait.Automatable.html#tymethod.normalized
+pub trait Automatable {
+    /// Returns the value of the parameter as a [`Normal`].
+    ///
+    /// [`Normal`]: ../normal/struct.Normal.html
+    fn normalized(&self) -> Normal;
+
+    /// Sets the value of the parameter from a [`Normal`].
+    ///
+    /// [`Normal`]: ../normal/struct.Normal.html
+    fn set_normalized(&mut self, normal: Normal);
+}
+
+impl Automatable for NormalParam {
+    fn normalized(&self) -> Normal {
+        self.value
+    }
+
+    fn set_normalized(&mut self, normal: Normal) {
+        self.value = normal;
+    }
+}
+
+/// A range that maps plain values to and from a [`Normal`]
+///
+/// [`Normal`]: ../normal/struct.Normal.html
+pub trait MapRange {
+    /// The type of the plain value
+    type Value: Copy;
+
+    /// Returns the corresponding [`Normal`] from the supplied value.
+    ///
+    /// [`Normal`]: ../normal/struct.Normal.html
+    fn map_to_normal(&self, value: Self::Value) -> Normal;
+
+    /// Returns the corresponding value from the supplied [`Normal`].
+    ///
+    /// [`Normal`]: ../normal/struct.Normal.html
+    fn unmap_to_value(&self, normal: Normal) -> Self::Value;
+}
+
+impl MapRange for FloatRange {
+    type Value = f32;
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        FloatRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        FloatRange::unmap_to_value(self, normal)
+    }
+}
+
+impl MapRange for IntRange {
+    type Value = i32;
+
+    fn map_to_normal(&self, value: i32) -> Normal {
+        IntRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> i32 {
+        IntRange::unmap_to_value(self, normal)
+    }
+}
+
+impl MapRange for LogDBRange {
+    type Value = f32;
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        LogDBRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        LogDBRange::unmap_to_value(self, normal)
+    }
+}
+
+impl MapRange for FreqRange {
+    type Value = f32;
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        FreqRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        FreqRange::unmap_to_value(self, normal)
+    }
+}
+
+impl MapRange for NoteDivisionRange {
+    type Value = NoteDivision;
+
+    fn map_to_normal(&self, value: NoteDivision) -> Normal {
+        NoteDivisionRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> NoteDivision {
+        NoteDivisionRange::unmap_to_value(self, normal)
+    }
+}
+
+impl MapRange for PanRange {
+    type Value = f32;
+
+    fn map_to_normal(&self, value: f32) -> Normal {
+        PanRange::map_to_normal(self, value)
+    }
+
+    fn unmap_to_value(&self, normal: Normal) -> f32 {
+        PanRange::unmap_to_value(self, normal)
+    }
+}
+
+/// A plain value of a parameter together with the range it is mapped with.
+///
+/// This implements [`Automatable`] for any [`MapRange`].
+///
+/// [`Automatable`]: trait.Automatable.html
+/// [`MapRange`]: trait.MapRange.html
+#[derive(Debug, Clone)]
+pub struct RangedParam<R: MapRange> {
+    /// The range the value is mapped with
+    pub range: R,
+    /// The plain value of the parameter
+    pub value: R::Value,
+}
+
+impl<R: MapRange> RangedParam<R> {
+    /// Creates a new `RangedParam` with the given range and plain value.
+    pub fn new(range: R, value: R::Value) -> Self {
+        Self { range, value }
+    }
+}
+
+impl<R: MapRange> Automatable for RangedParam<R> {
+    fn normalized(&self) -> Normal {
+        self.range.map_to_normal(self.value)
+    }
+
+    fn set_normalized(&mut self, normal: Normal) {
+        self.value = self.range.unmap_to_value(normal);
+    }
+}
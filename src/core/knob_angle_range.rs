@@ -2,6 +2,7 @@
 //! will rotate.
 
 use super::math::{PI_OVER_180, TWO_PI};
+use super::Normal;
 
 /// The default minimum angle of a rotating widget such as a Knob
 pub static DEFAULT_ANGLE_MIN: f32 = 30.0 * PI_OVER_180;
@@ -88,6 +89,30 @@ impl KnobAngleRange {
         Self { min, max }
     }
 
+    /// A range with the given `sweep` (in degrees) that is centered around
+    /// the top of the knob (12 o'clock), so the center value of a bipolar
+    /// knob points straight up.
+    ///
+    /// Values of `sweep` < `0.0` will be set to `0.0`, and values >= `360.0`
+    /// will be set to just under `360.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::{KnobAngleRange, Normal};
+    ///
+    /// let angle_range = KnobAngleRange::centered(270.0);
+    ///
+    /// let angle = angle_range.normal_to_angle(Normal::center());
+    /// assert!((angle - std::f32::consts::PI).abs() < 0.0001);
+    /// assert!((angle_range.span().to_degrees() - 270.0).abs() < 0.001);
+    /// ```
+    pub fn centered(sweep: f32) -> Self {
+        let half_sweep = sweep.max(0.0).min(359.99) / 2.0;
+
+        Self::from_deg(180.0 - half_sweep, 180.0 + half_sweep)
+    }
+
     /// returns the minimum angle (between `0.0` and `TWO_PI` in radians)
     pub fn min(&self) -> f32 {
         self.min
@@ -96,4 +121,52 @@ impl KnobAngleRange {
     pub fn max(&self) -> f32 {
         self.max
     }
+    /// returns the span between the minimum and maximum angle (in radians)
+    pub fn span(&self) -> f32 {
+        self.max - self.min
+    }
+
+    /// Returns the angle (in radians) a knob points to for the given
+    /// [`Normal`].
+    ///
+    /// `0.0` radians points straight down at the bottom of the knob, with the
+    /// angles rotating clockwise towards `TWO_PI` (`2*PI`) radians. Add
+    /// `FRAC_PI_2` to get the angle used by canvas paths, where `0.0` radians
+    /// points to the right.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::{KnobAngleRange, Normal};
+    ///
+    /// let angle_range = KnobAngleRange::from_deg(30.0, 330.0);
+    ///
+    /// let angle = angle_range.normal_to_angle(Normal::center());
+    /// assert!((angle - std::f32::consts::PI).abs() < 0.0001);
+    ///
+    /// let normal = angle_range.angle_to_normal(angle);
+    /// assert!((normal.as_f32() - 0.5).abs() < 0.0001);
+    /// ```
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn normal_to_angle(&self, normal: Normal) -> f32 {
+        self.min + normal.scale(self.span())
+    }
+
+    /// Returns the [`Normal`] for the given angle (in radians) a knob points
+    /// to. Angles outside of the range are clamped.
+    ///
+    /// `0.0` radians points straight down at the bottom of the knob, with the
+    /// angles rotating clockwise towards `TWO_PI` (`2*PI`) radians.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn angle_to_normal(&self, angle: f32) -> Normal {
+        let span = self.span();
+
+        if span <= 0.0 {
+            return Normal::min();
+        }
+
+        Normal::new((angle - self.min) / span)
+    }
 }
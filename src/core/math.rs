@@ -11,23 +11,77 @@ static ONE_OVER_20_F32: f32 = 1.0 / 20.0;
 static ONE_OVER_20_F64: f64 = 1.0 / 20.0;
 
 /// Converts decibels to amplitude
+///
+/// `-inf` decibels is converted to an amplitude of `0.0`.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::core::math::{amplitude_to_db_f32, db_to_amplitude_f32};
+///
+/// for &amp in &[0.001, 0.25, 0.5, 1.0, 2.0] {
+///     let db = amplitude_to_db_f32(amp);
+///     assert!((db_to_amplitude_f32(db) - amp).abs() < 0.0001 * amp);
+/// }
+///
+/// assert_eq!(db_to_amplitude_f32(std::f32::NEG_INFINITY), 0.0);
+/// assert_eq!(amplitude_to_db_f32(0.0), std::f32::NEG_INFINITY);
+/// ```
 #[inline]
-pub fn db_to_amplitdue_f32(db: f32) -> f32 {
+pub fn db_to_amplitude_f32(db: f32) -> f32 {
     10.0f32.powf(db * ONE_OVER_20_F32)
 }
 /// Converts decibels to amplitude
+///
+/// `-inf` decibels is converted to an amplitude of `0.0`.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::core::math::{amplitude_to_db_f64, db_to_amplitude_f64};
+///
+/// for &amp in &[0.001, 0.25, 0.5, 1.0, 2.0] {
+///     let db = amplitude_to_db_f64(amp);
+///     assert!((db_to_amplitude_f64(db) - amp).abs() < 0.000001 * amp);
+/// }
+///
+/// assert_eq!(db_to_amplitude_f64(std::f64::NEG_INFINITY), 0.0);
+/// ```
 #[inline]
-pub fn db_to_amplitdue_f64(db: f64) -> f64 {
+pub fn db_to_amplitude_f64(db: f64) -> f64 {
     10.0f64.powf(db * ONE_OVER_20_F64)
 }
 
 /// Converts amplitude to decibels
+///
+/// An amplitude of `0.0` is converted to `-inf` decibels.
 #[inline]
 pub fn amplitude_to_db_f32(amp: f32) -> f32 {
     20.0f32 * amp.log10()
 }
 /// Converts amplitude to decibels
+///
+/// An amplitude of `0.0` is converted to `-inf` decibels.
 #[inline]
-pub fn amplitdue_to_db_f64(amp: f64) -> f64 {
+pub fn amplitude_to_db_f64(amp: f64) -> f64 {
     20.0f64 * amp.log10()
 }
+
+/// Converts decibels to amplitude
+#[deprecated(note = "use `db_to_amplitude_f32` instead")]
+#[inline]
+pub fn db_to_amplitdue_f32(db: f32) -> f32 {
+    db_to_amplitude_f32(db)
+}
+/// Converts decibels to amplitude
+#[deprecated(note = "use `db_to_amplitude_f64` instead")]
+#[inline]
+pub fn db_to_amplitdue_f64(db: f64) -> f64 {
+    db_to_amplitude_f64(db)
+}
+/// Converts amplitude to decibels
+#[deprecated(note = "use `amplitude_to_db_f64` instead")]
+#[inline]
+pub fn amplitdue_to_db_f64(amp: f64) -> f64 {
+    amplitude_to_db_f64(amp)
+}
@@ -3,17 +3,21 @@
 //! This module holds basic types that can be reused and re-exported in
 //! different runtime implementations.
 
+pub mod automatable;
 pub mod knob_angle_range;
 pub mod math;
 pub mod modulation_range;
 pub mod normal;
 pub mod normal_param;
+pub mod note_division;
 pub mod offset;
 pub mod range;
 
+pub use automatable::{Automatable, MapRange, RangedParam};
 pub use knob_angle_range::*;
 pub use modulation_range::ModulationRange;
-pub use normal::Normal;
+pub use normal::{Normal, NormalFloat};
 pub use normal_param::NormalParam;
+pub use note_division::{NoteDivision, NoteModifier};
 pub use offset::Offset;
 pub use range::*;
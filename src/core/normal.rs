@@ -6,6 +6,9 @@
 ///
 /// `0.0 >= value <= 1.0`
 ///
+/// The value is stored as an `f64` instead when the `f64-normal` feature is
+/// enabled. See [`NormalFloat`].
+///
 /// # Example
 ///
 /// ```
@@ -20,11 +23,31 @@
 /// normal.set(0.5);
 /// assert_eq!(normal.as_f32(), 0.5);
 /// ```
+///
+/// [`NormalFloat`]: type.NormalFloat.html
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub struct Normal {
-    value: f32,
+    value: NormalFloat,
 }
 
+/// The floating point type a [`Normal`] stores its value as.
+///
+/// This is `f32` by default, or `f64` when the `f64-normal` feature is
+/// enabled for extra precision on long fader throws and fine automation.
+///
+/// [`Normal`]: struct.Normal.html
+#[cfg(not(feature = "f64-normal"))]
+pub type NormalFloat = f32;
+
+/// The floating point type a [`Normal`] stores its value as.
+///
+/// This is `f32` by default, or `f64` when the `f64-normal` feature is
+/// enabled for extra precision on long fader throws and fine automation.
+///
+/// [`Normal`]: struct.Normal.html
+#[cfg(feature = "f64-normal")]
+pub type NormalFloat = f64;
+
 impl Default for Normal {
     fn default() -> Self {
         Self { value: 0.0 }
@@ -44,6 +67,14 @@ impl Normal {
     ///
     /// else `normal.value` is set to `value`
     pub fn new(value: f32) -> Self {
+        Self::from_float(value as NormalFloat)
+    }
+
+    /// Creates a new `Normal` from a [`NormalFloat`], clamping it to the
+    /// range `[0.0, 1.0]`.
+    ///
+    /// [`NormalFloat`]: type.NormalFloat.html
+    pub fn from_float(value: NormalFloat) -> Self {
         Self {
             value: {
                 if value < 0.0 {
@@ -85,40 +116,178 @@ impl Normal {
     /// else `normal.value` is set to `value`
     #[inline]
     pub fn set(&mut self, value: f32) {
-        self.value = {
-            if value < 0.0 {
-                0.0
-            } else if value > 1.0 {
-                1.0
-            } else {
-                value
-            }
-        }
+        *self = Self::from_float(value as NormalFloat);
+    }
+
+    /// Returns the value of the `Normal` as a [`NormalFloat`], which keeps
+    /// the full precision of the `f64-normal` feature.
+    ///
+    /// [`NormalFloat`]: type.NormalFloat.html
+    #[inline]
+    pub fn value(&self) -> NormalFloat {
+        self.value
     }
 
     /// Returns the value of the `Normal` as an `f32`
     #[inline]
     pub fn as_f32(&self) -> f32 {
-        self.value
+        self.value as f32
     }
 
     /// Returns the inverse value (`1.0 - value`) of the `Normal` as an `f32`
     #[inline]
     pub fn as_f32_inv(&self) -> f32 {
-        1.0 - self.value
+        (1.0 - self.value) as f32
     }
 
     /// Returns the value of the `Normal` times the `scalar`
+    ///
+    /// An `HSlider` uses this to map its value onto the horizontal axis only,
+    /// with `scalar` being the width it can travel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::Normal;
+    ///
+    /// let normal = Normal::new(0.25);
+    /// assert_eq!(normal.scale(200.0), 50.0);
+    /// assert_eq!(Normal::min().scale(200.0), 0.0);
+    /// assert_eq!(Normal::max().scale(200.0), 200.0);
+    /// ```
     #[inline]
     pub fn scale(&self, scalar: f32) -> f32 {
-        self.value * scalar
+        (self.value * scalar as NormalFloat) as f32
     }
 
     /// Returns the inverse value (`1.0 - value`) of the `Normal`
     /// times the `scalar`
+    ///
+    /// A `VSlider` uses this to map its value onto the vertical axis only,
+    /// with `scalar` being the height it can travel. The value is inverted
+    /// because the y axis points down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::Normal;
+    ///
+    /// let normal = Normal::new(0.25);
+    /// assert_eq!(normal.scale_inv(200.0), 150.0);
+    /// assert_eq!(Normal::min().scale_inv(200.0), 200.0);
+    /// assert_eq!(Normal::max().scale_inv(200.0), 0.0);
+    /// ```
     #[inline]
     pub fn scale_inv(&self, scalar: f32) -> f32 {
-        (1.0 - self.value) * scalar
+        ((1.0 - self.value) * scalar as NormalFloat) as f32
+    }
+
+    /// Returns a new `Normal` with `amount` added to the value, clamped to
+    /// the range `[0.0, 1.0]`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::Normal;
+    ///
+    /// let normal = Normal::new(0.75);
+    /// assert_eq!(normal.clamped_add(0.125).as_f32(), 0.875);
+    /// assert_eq!(normal.clamped_add(0.5).as_f32(), 1.0);
+    /// assert_eq!(Normal::max().clamped_add(0.1).as_f32(), 1.0);
+    /// ```
+    #[inline]
+    pub fn clamped_add(&self, amount: f32) -> Self {
+        Self::from_float(self.value + amount as NormalFloat)
+    }
+
+    /// Returns a new `Normal` with `amount` subtracted from the value,
+    /// clamped to the range `[0.0, 1.0]`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::Normal;
+    ///
+    /// let normal = Normal::new(0.25);
+    /// assert_eq!(normal.clamped_sub(0.125).as_f32(), 0.125);
+    /// assert_eq!(normal.clamped_sub(0.5).as_f32(), 0.0);
+    /// assert_eq!(Normal::min().clamped_sub(0.1).as_f32(), 0.0);
+    /// ```
+    #[inline]
+    pub fn clamped_sub(&self, amount: f32) -> Self {
+        Self::from_float(self.value - amount as NormalFloat)
+    }
+
+    /// Linearly maps `value` from the range `from` to the range `to`.
+    ///
+    /// The result is not clamped, and mapping back with the ranges swapped
+    /// returns the original value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the value to map
+    /// * `from` - the `(start, end)` of the range `value` is in
+    /// * `to` - the `(start, end)` of the range to map `value` to
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::Normal;
+    ///
+    /// let mapped = Normal::map_range(0.25, (0.0, 1.0), (-12.0, 12.0));
+    /// assert_eq!(mapped, -6.0);
+    ///
+    /// let value = Normal::map_range(mapped, (-12.0, 12.0), (0.0, 1.0));
+    /// assert_eq!(value, 0.25);
+    /// ```
+    #[inline]
+    pub fn map_range(value: f32, from: (f32, f32), to: (f32, f32)) -> f32 {
+        let from_span = from.1 - from.0;
+
+        if from_span == 0.0 {
+            return to.0;
+        }
+
+        to.0 + ((value - from.0) / from_span) * (to.1 - to.0)
+    }
+
+    /// Creates a new `Normal` from a value in decibels, linearly mapping the
+    /// range `[floor_db, ceiling_db]` to `[0.0, 1.0]`.
+    ///
+    /// Values outside of the range, including `-inf` decibels, are clamped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::Normal;
+    ///
+    /// assert_eq!(Normal::from_db(-6.0, -12.0, 0.0).as_f32(), 0.5);
+    /// assert_eq!(Normal::from_db(6.0, -12.0, 0.0).as_f32(), 1.0);
+    ///
+    /// let silence = Normal::from_db(std::f32::NEG_INFINITY, -60.0, 0.0);
+    /// assert_eq!(silence.as_f32(), 0.0);
+    /// ```
+    pub fn from_db(db: f32, floor_db: f32, ceiling_db: f32) -> Self {
+        if db <= floor_db {
+            return Self::min();
+        }
+
+        Self::new(Self::map_range(db, (floor_db, ceiling_db), (0.0, 1.0)))
+    }
+
+    /// Returns the value in decibels of the `Normal`, linearly mapping
+    /// `[0.0, 1.0]` to the range `[floor_db, ceiling_db]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::Normal;
+    ///
+    /// let normal = Normal::from_db(-18.0, -24.0, 0.0);
+    /// assert!((normal.to_db(-24.0, 0.0) - -18.0).abs() < 0.0001);
+    /// ```
+    pub fn to_db(&self, floor_db: f32, ceiling_db: f32) -> f32 {
+        Self::map_range(self.as_f32(), (0.0, 1.0), (floor_db, ceiling_db))
     }
 }
 
@@ -130,6 +299,6 @@ impl From<f32> for Normal {
 
 impl From<Normal> for f32 {
     fn from(normal: Normal) -> f32 {
-        normal.value
+        normal.as_f32()
     }
 }
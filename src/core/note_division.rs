@@ -0,0 +1,97 @@
+//! Musical note divisions used by tempo-synced parameters
+
+/// The rhythmic modifier of a [`NoteDivision`]
+///
+/// [`NoteDivision`]: struct.NoteDivision.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum NoteModifier {
+    /// A regular note
+    Straight,
+    /// A dotted note, which is `1.5` times the length of a regular note
+    Dotted,
+    /// A triplet note, which is `2/3` times the length of a regular note
+    Triplet,
+}
+
+impl Default for NoteModifier {
+    fn default() -> Self {
+        NoteModifier::Straight
+    }
+}
+
+/// A musical note division, such as a quarter note (`1/4`) or a dotted
+/// eighth note (`1/8D`)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NoteDivision {
+    /// The length of the note as a fraction of a whole note. For example,
+    /// `4` is a quarter note.
+    pub denominator: u16,
+    /// The rhythmic modifier of the note
+    pub modifier: NoteModifier,
+}
+
+impl NoteDivision {
+    /// Creates a new `NoteDivision`.
+    ///
+    /// # Arguments
+    ///
+    /// * `denominator` - the length of the note as a fraction of a whole note.
+    /// For example, `4` is a quarter note.
+    /// * `modifier` - the rhythmic modifier of the note
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `denominator` is `0`
+    pub fn new(denominator: u16, modifier: NoteModifier) -> Self {
+        assert!(denominator != 0);
+
+        Self {
+            denominator,
+            modifier,
+        }
+    }
+
+    /// Creates a new regular `NoteDivision`
+    pub fn straight(denominator: u16) -> Self {
+        Self::new(denominator, NoteModifier::Straight)
+    }
+
+    /// Creates a new dotted `NoteDivision`
+    pub fn dotted(denominator: u16) -> Self {
+        Self::new(denominator, NoteModifier::Dotted)
+    }
+
+    /// Creates a new triplet `NoteDivision`
+    pub fn triplet(denominator: u16) -> Self {
+        Self::new(denominator, NoteModifier::Triplet)
+    }
+
+    /// Returns the length of the note in beats, where a beat is a quarter
+    /// note
+    pub fn beats(&self) -> f32 {
+        let beats = 4.0 / f32::from(self.denominator);
+
+        match self.modifier {
+            NoteModifier::Straight => beats,
+            NoteModifier::Dotted => beats * 1.5,
+            NoteModifier::Triplet => beats * (2.0 / 3.0),
+        }
+    }
+
+    /// Returns the length of the note in seconds at the given tempo
+    ///
+    /// * `bpm` - the tempo in quarter note beats per minute
+    pub fn seconds(&self, bpm: f32) -> f32 {
+        self.beats() * 60.0 / bpm
+    }
+
+    /// Returns a label for the note, such as `"1/4"`, `"1/8D"` for a dotted
+    /// note, or `"1/16T"` for a triplet note
+    pub fn label(&self) -> String {
+        match self.modifier {
+            NoteModifier::Straight => format!("1/{}", self.denominator),
+            NoteModifier::Dotted => format!("1/{}D", self.denominator),
+            NoteModifier::Triplet => format!("1/{}T", self.denominator),
+        }
+    }
+}
@@ -39,6 +39,17 @@ impl Offset {
         f32::from(self.y)
     }
 
+    /// Returns the [`Offset`] with both values multiplied by `scale`, rounded
+    /// to the nearest pixel.
+    ///
+    /// [`Offset`]: struct.Offset.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        Self {
+            x: (f32::from(self.x) * scale).round() as i16,
+            y: (f32::from(self.y) * scale).round() as i16,
+        }
+    }
+
     /// Return an offsetted rectangle.
     #[inline]
     pub fn offset_rect(&self, rect: &Rectangle) -> Rectangle {
@@ -2,20 +2,79 @@
 use crate::core::normal_param::NormalParam;
 ///
 /// [`Normal`]: ../struct.Normal.html
-use crate::core::Normal;
+use crate::core::{Normal, NoteDivision};
 
 use std::fmt::Debug;
 
-/// A range that maps a continuous linear range of `f32` values
-/// to a [`Normal`]
+/// The curve used by a [`FloatRange`] to map between a value and a
+/// [`Normal`]
 ///
+/// [`FloatRange`]: struct.FloatRange.html
 /// [`Normal`]: ../struct.Normal.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Curve {
+    /// A linear mapping.
+    Linear,
+    /// An exponential mapping with the given exponent. An exponent greater
+    /// than `1.0` gives finer control near the minimum of the range, and an
+    /// exponent less than `1.0` gives finer control near the maximum.
+    Exponential(f32),
+    /// A symmetric s-shaped mapping with the given exponent. An exponent
+    /// greater than `1.0` gives finer control near both ends of the range,
+    /// and an exponent less than `1.0` gives finer control near the center.
+    SCurve(f32),
+}
+
+impl Default for Curve {
+    fn default() -> Self {
+        Curve::Linear
+    }
+}
+
+impl Curve {
+    /// Maps a linear position in the range `[0.0, 1.0]` through the curve.
+    fn apply(&self, t: f32, exponent: impl Fn(f32) -> f32) -> f32 {
+        match self {
+            Curve::Linear => t,
+            Curve::Exponential(e) => t.powf(exponent(*e)),
+            Curve::SCurve(e) => {
+                if t < 0.5 {
+                    0.5 * (2.0 * t).powf(exponent(*e))
+                } else {
+                    1.0 - (0.5 * (2.0 * (1.0 - t)).powf(exponent(*e)))
+                }
+            }
+        }
+    }
+
+    /// Maps a [`Normal`] to a linear position in the range `[0.0, 1.0]`.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn map_to_linear(&self, normal: f32) -> f32 {
+        self.apply(normal, |e| e)
+    }
+
+    /// Maps a linear position in the range `[0.0, 1.0]` to a [`Normal`].
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    fn map_from_linear(&self, t: f32) -> f32 {
+        self.apply(t, f32::recip)
+    }
+}
+
+/// A range that maps a continuous range of `f32` values to a [`Normal`],
+/// either linearly or through a [`Curve`]
+///
+/// [`Normal`]: ../struct.Normal.html
+/// [`Curve`]: enum.Curve.html
 #[derive(Debug, Copy, Clone)]
 pub struct FloatRange {
     min: f32,
     max: f32,
     span: f32,
     span_recip: f32,
+    unit: &'static str,
+    curve: Curve,
 }
 
 impl FloatRange {
@@ -40,9 +99,86 @@ impl FloatRange {
             max,
             span,
             span_recip,
+            unit: "",
+            curve: Curve::Linear,
+        }
+    }
+
+    /// Creates a new `FloatRange` that maps values through a [`Curve`]
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - the minimum of the range (inclusive)
+    /// * `max` - the maximum of the range (inclusive)
+    /// * `curve` - the [`Curve`] of the mapping
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `max` <= `min`, or if the exponent of the
+    /// [`Curve`] is not greater than `0.0`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::{Curve, FloatRange, Normal};
+    ///
+    /// for curve in &[
+    ///     Curve::Linear,
+    ///     Curve::Exponential(3.0),
+    ///     Curve::SCurve(2.0),
+    ///     Curve::SCurve(0.5),
+    /// ] {
+    ///     let range = FloatRange::with_curve(-10.0, 10.0, *curve);
+    ///
+    ///     let mut prev = range.unmap_to_value(Normal::min());
+    ///     assert_eq!(prev, -10.0);
+    ///
+    ///     for i in 1..=100 {
+    ///         let normal = Normal::new(i as f32 / 100.0);
+    ///         let value = range.unmap_to_value(normal);
+    ///
+    ///         // The mapping is monotonic...
+    ///         assert!(value > prev);
+    ///         prev = value;
+    ///
+    ///         // ...and round trips.
+    ///         let round_trip = range.map_to_normal(value).as_f32();
+    ///         assert!((round_trip - normal.as_f32()).abs() < 0.0001);
+    ///     }
+    ///
+    ///     assert!((prev - 10.0).abs() < 0.0001);
+    /// }
+    /// ```
+    ///
+    /// [`Curve`]: enum.Curve.html
+    pub fn with_curve(min: f32, max: f32, curve: Curve) -> Self {
+        match curve {
+            Curve::Linear => {}
+            Curve::Exponential(e) | Curve::SCurve(e) => assert!(e > 0.0),
+        }
+
+        Self {
+            curve,
+            ..FloatRange::new(min, max)
         }
     }
 
+    /// Sets the unit suffix used by [`format_value`], such as `"ms"` or
+    /// `"%"`.
+    ///
+    /// [`format_value`]: #method.format_value
+    pub fn with_unit(mut self, unit: &'static str) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Returns the [`Curve`] of the mapping
+    ///
+    /// [`Curve`]: enum.Curve.html
+    pub fn curve(&self) -> Curve {
+        self.curve
+    }
+
     /// A `FloatRange` with the range
     ///
     /// * `min` = -1.0
@@ -86,19 +222,72 @@ impl FloatRange {
         }
     }
 
+    /// Sets the value of a [`NormalParam`] from a value in this range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::FloatRange;
+    ///
+    /// let range = FloatRange::new(-12.0, 12.0);
+    /// let mut param = range.default_normal_param();
+    ///
+    /// range.set_param(&mut param, 6.0);
+    /// assert_eq!(param.value.as_f32(), 0.75);
+    /// assert_eq!(range.param_value(&param), 6.0);
+    /// ```
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn set_param(&self, param: &mut NormalParam, value: f32) {
+        param.value = self.map_to_normal(value);
+    }
+
+    /// Returns the value in this range of a [`NormalParam`].
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn param_value(&self, param: &NormalParam) -> f32 {
+        self.unmap_to_value(param.value)
+    }
+
     /// Returns the corresponding [`Normal`] from the supplied value
     ///
     /// [`Normal`]: ../struct.Normal.html
     pub fn map_to_normal(&self, value: f32) -> Normal {
         let value = self.constrain(value);
-        ((value - self.min) * self.span_recip).into()
+        self.curve
+            .map_from_linear((value - self.min) * self.span_recip)
+            .into()
     }
 
     /// Returns the corresponding value from the supplied [`Normal`]
     ///
     /// [`Normal`]: ../struct.Normal.html
     pub fn unmap_to_value(&self, normal: Normal) -> f32 {
-        (normal.as_f32() * self.span) + self.min
+        (self.curve.map_to_linear(normal.as_f32()) * self.span) + self.min
+    }
+
+    /// Formats a value of this range as a string with two decimal places,
+    /// followed by the unit suffix if one was set with [`with_unit`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::FloatRange;
+    ///
+    /// let range = FloatRange::new(0.0, 10.0);
+    /// assert_eq!(range.format_value(2.5), "2.50");
+    ///
+    /// let range = FloatRange::new(0.0, 10.0).with_unit("ms");
+    /// assert_eq!(range.format_value(2.5), "2.50 ms");
+    /// ```
+    ///
+    /// [`with_unit`]: #method.with_unit
+    pub fn format_value(&self, value: f32) -> String {
+        if self.unit.is_empty() {
+            format!("{:.2}", value)
+        } else {
+            format!("{:.2} {}", value, self.unit)
+        }
     }
 }
 
@@ -115,6 +304,7 @@ pub struct IntRange {
     max: i32,
     span: f32,
     span_recip: f32,
+    unit: &'static str,
 }
 
 impl IntRange {
@@ -139,9 +329,36 @@ impl IntRange {
             max,
             span,
             span_recip,
+            unit: "",
         }
     }
 
+    /// Sets the unit suffix used by [`format_value`], such as `"ms"` or
+    /// `"%"`.
+    ///
+    /// [`format_value`]: #method.format_value
+    pub fn with_unit(mut self, unit: &'static str) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Creates a new `IntRange` with one step for each label, from `0` to
+    /// `labels.len() - 1`
+    ///
+    /// A matching group of text marks can be created with
+    /// [`enumerated_text_marks`].
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `labels` contains less than two labels
+    ///
+    /// [`enumerated_text_marks`]: #method.enumerated_text_marks
+    pub fn enumerated(labels: &[&str]) -> Self {
+        assert!(labels.len() > 1);
+
+        IntRange::new(0, labels.len() as i32 - 1)
+    }
+
     fn constrain(&self, value: i32) -> i32 {
         if value <= self.min {
             self.min
@@ -186,6 +403,20 @@ impl IntRange {
         *normal = self.map_to_normal(value_int);
     }
 
+    /// Sets the value of a [`NormalParam`] from a value in this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn set_param(&self, param: &mut NormalParam, value: i32) {
+        param.value = self.map_to_normal(value);
+    }
+
+    /// Returns the value in this range of a [`NormalParam`].
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn param_value(&self, param: &NormalParam) -> i32 {
+        self.unmap_to_value(param.value)
+    }
+
     /// Returns the corresponding [`Normal`] from the supplied value
     ///
     /// [`Normal`]: ../struct.Normal.html
@@ -200,6 +431,30 @@ impl IntRange {
     pub fn unmap_to_value(&self, normal: Normal) -> i32 {
         (normal.as_f32() * self.span).round() as i32 + self.min
     }
+
+    /// Formats a value of this range as a string, followed by the unit
+    /// suffix if one was set with [`with_unit`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::IntRange;
+    ///
+    /// let range = IntRange::new(0, 16);
+    /// assert_eq!(range.format_value(4), "4");
+    ///
+    /// let range = IntRange::new(0, 16).with_unit("voices");
+    /// assert_eq!(range.format_value(4), "4 voices");
+    /// ```
+    ///
+    /// [`with_unit`]: #method.with_unit
+    pub fn format_value(&self, value: i32) -> String {
+        if self.unit.is_empty() {
+            format!("{}", value)
+        } else {
+            format!("{} {}", value, self.unit)
+        }
+    }
 }
 
 impl Default for IntRange {
@@ -310,6 +565,20 @@ impl LogDBRange {
         }
     }
 
+    /// Sets the value of a [`NormalParam`] from a value in this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn set_param(&self, param: &mut NormalParam, value: f32) {
+        param.value = self.map_to_normal(value);
+    }
+
+    /// Returns the value in this range of a [`NormalParam`].
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn param_value(&self, param: &NormalParam) -> f32 {
+        self.unmap_to_value(param.value)
+    }
+
     /// Returns the corresponding [`Normal`] from the supplied `value`
     ///
     /// [`Normal`]: ../struct.Normal.html
@@ -367,6 +636,24 @@ impl LogDBRange {
             log_normal * self.max
         }
     }
+
+    /// Formats a dB value as a string with one decimal place, such as
+    /// `"-6.0 dB"`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::{LogDBRange, Normal};
+    ///
+    /// let range = LogDBRange::new(-12.0, 12.0, Normal::new(0.5));
+    ///
+    /// assert_eq!(range.format_value(-6.0), "-6.0 dB");
+    /// assert_eq!(range.format_value(0.0), "0.0 dB");
+    /// assert_eq!(range.format_value(3.24), "3.2 dB");
+    /// ```
+    pub fn format_value(&self, value: f32) -> String {
+        format!("{:.1} dB", value)
+    }
 }
 
 impl Default for LogDBRange {
@@ -469,6 +756,20 @@ impl FreqRange {
         }
     }
 
+    /// Sets the value of a [`NormalParam`] from a value in this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn set_param(&self, param: &mut NormalParam, value: f32) {
+        param.value = self.map_to_normal(value);
+    }
+
+    /// Returns the value in this range of a [`NormalParam`].
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn param_value(&self, param: &NormalParam) -> f32 {
+        self.unmap_to_value(param.value)
+    }
+
     /// Returns the corresponding [`Normal`] from the supplied frequency value
     ///
     /// [`Normal`]: ../struct.Normal.html
@@ -491,6 +792,59 @@ impl FreqRange {
 
         octave_normal_to_spectrum(spectrum_normal)
     }
+
+    /// Formats a frequency value as a string, such as `"440.0 Hz"` or
+    /// `"1.0 kHz"`. Values that round to 1000.0 Hz or above are shown in kHz.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::FreqRange;
+    ///
+    /// let range = FreqRange::new(20.0, 20000.0);
+    ///
+    /// assert_eq!(range.format_value(440.0), "440.0 Hz");
+    /// assert_eq!(range.format_value(999.94), "999.9 Hz");
+    /// assert_eq!(range.format_value(999.96), "1.0 kHz");
+    /// assert_eq!(range.format_value(12500.0), "12.5 kHz");
+    /// ```
+    pub fn format_value(&self, value: f32) -> String {
+        // Pick the unit from the value as it will be displayed, so that
+        // 999.96 Hz is not shown as "1000.0 Hz".
+        if (value * 10.0).round() < 10000.0 {
+            format!("{:.1} Hz", value)
+        } else {
+            format!("{:.1} kHz", value / 1000.0)
+        }
+    }
+
+    /// Returns the decades and their `2` and `5` subdivisions between
+    /// `start_hz` and `end_hz`, along with whether each one is a decade
+    pub(crate) fn decade_frequencies(
+        &self,
+        start_hz: f32,
+        end_hz: f32,
+    ) -> Vec<(f32, bool)> {
+        let start_hz = start_hz.max(self.min);
+        let end_hz = end_hz.min(self.max);
+
+        let mut frequencies: Vec<(f32, bool)> = Vec::new();
+
+        let mut decade = 10.0;
+        while decade <= end_hz {
+            for multiplier in [1.0, 2.0, 5.0].iter() {
+                let freq = decade * multiplier;
+
+                if freq >= start_hz && freq <= end_hz {
+                    frequencies.push((freq, *multiplier == 1.0));
+                }
+            }
+
+            decade *= 10.0;
+        }
+
+        frequencies
+    }
 }
 
 impl Default for FreqRange {
@@ -499,6 +853,348 @@ impl Default for FreqRange {
     }
 }
 
+/// A range that defines a discrete range of musical [`NoteDivision`]s,
+/// such as for the rate of a tempo-synced LFO
+///
+/// Like an [`IntRange`], widgets controlling this range will "step" when the
+/// [`Normal`] is snapped with [`snap`].
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{NoteDivision, NoteDivisionRange};
+///
+/// let range = NoteDivisionRange::new(&[
+///     NoteDivision::straight(1),
+///     NoteDivision::straight(2),
+///     NoteDivision::straight(4),
+/// ]);
+///
+/// let normal = range.map_to_normal(NoteDivision::straight(2));
+/// assert_eq!(normal.as_f32(), 0.5);
+/// assert_eq!(range.unmap_to_value(normal).label(), "1/2");
+/// ```
+///
+/// [`NoteDivision`]: ../note_division/struct.NoteDivision.html
+/// [`IntRange`]: struct.IntRange.html
+/// [`Normal`]: ../struct.Normal.html
+/// [`snap`]: #method.snap
+#[derive(Debug, Clone)]
+pub struct NoteDivisionRange {
+    divisions: Vec<NoteDivision>,
+    span: f32,
+    span_recip: f32,
+}
+
+impl NoteDivisionRange {
+    /// Creates a new `NoteDivisionRange`
+    ///
+    /// # Arguments
+    ///
+    /// * `divisions` - the note divisions in the order they appear in the
+    /// range, starting from the minimum position
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `divisions` contains less than two divisions
+    pub fn new(divisions: &[NoteDivision]) -> Self {
+        assert!(divisions.len() > 1);
+
+        let span = (divisions.len() - 1) as f32;
+        let span_recip = span.recip();
+
+        Self {
+            divisions: divisions.to_vec(),
+            span,
+            span_recip,
+        }
+    }
+
+    /// Returns the note divisions in this range
+    pub fn divisions(&self) -> &[NoteDivision] {
+        &self.divisions
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital value of the parameter.
+    /// * `default_value` - The default value of the parameter.
+    pub fn normal_param(
+        &self,
+        value: NoteDivision,
+        default: NoteDivision,
+    ) -> NormalParam {
+        NormalParam {
+            value: self.map_to_normal(value),
+            default: self.map_to_normal(default),
+        }
+    }
+
+    /// Snaps a [`Normal`] to the closest note division in this range.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn snap(&self, normal: &mut Normal) {
+        let index = self.unmap_to_index(*normal);
+        *normal = self.index_to_normal(index);
+    }
+
+    /// Sets the value of a [`NormalParam`] from a value in this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn set_param(&self, param: &mut NormalParam, value: NoteDivision) {
+        param.value = self.map_to_normal(value);
+    }
+
+    /// Returns the value in this range of a [`NormalParam`].
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn param_value(&self, param: &NormalParam) -> NoteDivision {
+        self.unmap_to_value(param.value)
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied note
+    /// division. If the division is not in this range, then the division
+    /// closest in length is used.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: NoteDivision) -> Normal {
+        let index = if let Some(index) =
+            self.divisions.iter().position(|d| *d == value)
+        {
+            index
+        } else {
+            let beats = value.beats();
+
+            let mut closest_index = 0;
+            let mut closest_diff = std::f32::MAX;
+
+            for (index, division) in self.divisions.iter().enumerate() {
+                let diff = (division.beats() - beats).abs();
+                if diff < closest_diff {
+                    closest_index = index;
+                    closest_diff = diff;
+                }
+            }
+
+            closest_index
+        };
+
+        self.index_to_normal(index)
+    }
+
+    /// Returns the corresponding note division from the supplied
+    /// [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> NoteDivision {
+        self.divisions[self.unmap_to_index(normal)]
+    }
+
+    pub(crate) fn index_to_normal(&self, index: usize) -> Normal {
+        (index as f32 * self.span_recip).into()
+    }
+
+    fn unmap_to_index(&self, normal: Normal) -> usize {
+        (normal.as_f32() * self.span).round() as usize
+    }
+}
+
+impl Default for NoteDivisionRange {
+    /// A range of common note divisions from a whole note to a
+    /// thirty-second note, ordered from longest to shortest
+    fn default() -> Self {
+        NoteDivisionRange::new(&[
+            NoteDivision::straight(1),
+            NoteDivision::dotted(2),
+            NoteDivision::straight(2),
+            NoteDivision::dotted(4),
+            NoteDivision::triplet(2),
+            NoteDivision::straight(4),
+            NoteDivision::dotted(8),
+            NoteDivision::triplet(4),
+            NoteDivision::straight(8),
+            NoteDivision::dotted(16),
+            NoteDivision::triplet(8),
+            NoteDivision::straight(16),
+            NoteDivision::triplet(16),
+            NoteDivision::straight(32),
+        ])
+    }
+}
+
+/// The pan law used by a [`PanRange`] to map a pan position to the gains of
+/// the left and right channels
+///
+/// [`PanRange`]: struct.PanRange.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PanLaw {
+    /// A sine/cosine law that keeps the total power constant across the pan
+    /// range. Each channel is `-3 dB` in the center.
+    ConstantPower,
+    /// A compromise between `ConstantPower` and `Linear`. Each channel is
+    /// `-4.5 dB` in the center.
+    Compromise,
+    /// A linear law that keeps the sum of the gains constant across the pan
+    /// range. Each channel is `-6 dB` in the center.
+    Linear,
+    /// A balance control that leaves both channels at unity gain in the
+    /// center, and linearly attenuates the opposite channel when panned.
+    Balance,
+}
+
+impl Default for PanLaw {
+    fn default() -> Self {
+        PanLaw::ConstantPower
+    }
+}
+
+/// A range that maps a pan position between `-1.0` (hard left) and `1.0`
+/// (hard right) to a [`Normal`], and to the linear gains of the left and
+/// right channels following a [`PanLaw`]
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{Normal, PanLaw, PanRange};
+///
+/// let range = PanRange::new(PanLaw::ConstantPower);
+///
+/// let (left, right) = range.to_gains(Normal::center());
+/// assert!((left - std::f32::consts::FRAC_1_SQRT_2).abs() < 0.0001);
+/// assert!((right - std::f32::consts::FRAC_1_SQRT_2).abs() < 0.0001);
+///
+/// let (left, right) = range.to_gains(range.map_to_normal(1.0));
+/// assert!(left.abs() < 0.0001);
+/// assert!((right - 1.0).abs() < 0.0001);
+/// ```
+///
+/// [`Normal`]: ../struct.Normal.html
+/// [`PanLaw`]: enum.PanLaw.html
+#[derive(Debug, Copy, Clone, Default)]
+pub struct PanRange {
+    law: PanLaw,
+}
+
+impl PanRange {
+    /// Creates a new `PanRange` with the given [`PanLaw`]
+    ///
+    /// [`PanLaw`]: enum.PanLaw.html
+    pub fn new(law: PanLaw) -> Self {
+        Self { law }
+    }
+
+    /// Returns the [`PanLaw`] of the range
+    ///
+    /// [`PanLaw`]: enum.PanLaw.html
+    pub fn law(&self) -> PanLaw {
+        self.law
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    ///
+    /// * `value` - The inital pan position of the parameter.
+    /// * `default_value` - The default pan position of the parameter.
+    pub fn normal_param(&self, value: f32, default: f32) -> NormalParam {
+        NormalParam {
+            value: self.map_to_normal(value),
+            default: self.map_to_normal(default),
+        }
+    }
+
+    /// Creates a new [`NormalParam`] with values mapped
+    /// from this range where `value` and `default_value` is the center
+    /// (`0.0`).
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn default_normal_param(&self) -> NormalParam {
+        NormalParam {
+            value: Normal::center(),
+            default: Normal::center(),
+        }
+    }
+
+    /// Sets the value of a [`NormalParam`] from a pan position in this range.
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn set_param(&self, param: &mut NormalParam, value: f32) {
+        param.value = self.map_to_normal(value);
+    }
+
+    /// Returns the pan position in this range of a [`NormalParam`].
+    ///
+    /// [`NormalParam`]: ../normal_param/struct.NormalParam.html
+    pub fn param_value(&self, param: &NormalParam) -> f32 {
+        self.unmap_to_value(param.value)
+    }
+
+    /// Returns the corresponding [`Normal`] from the supplied pan position.
+    /// Positions outside of `-1.0` to `1.0` are clamped.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn map_to_normal(&self, value: f32) -> Normal {
+        ((value + 1.0) / 2.0).into()
+    }
+
+    /// Returns the corresponding pan position from the supplied [`Normal`]
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    pub fn unmap_to_value(&self, normal: Normal) -> f32 {
+        (normal.as_f32() * 2.0) - 1.0
+    }
+
+    /// Returns the linear gains `(left, right)` of the left and right
+    /// channels for the supplied [`Normal`], following the [`PanLaw`] of the
+    /// range.
+    ///
+    /// [`Normal`]: ../struct.Normal.html
+    /// [`PanLaw`]: enum.PanLaw.html
+    pub fn to_gains(&self, normal: Normal) -> (f32, f32) {
+        let t = normal.as_f32();
+        let angle = t * std::f32::consts::FRAC_PI_2;
+
+        match self.law {
+            PanLaw::ConstantPower => (angle.cos(), angle.sin()),
+            PanLaw::Compromise => {
+                (((1.0 - t) * angle.cos()).sqrt(), (t * angle.sin()).sqrt())
+            }
+            PanLaw::Linear => (1.0 - t, t),
+            PanLaw::Balance => ((2.0 - (2.0 * t)).min(1.0), (2.0 * t).min(1.0)),
+        }
+    }
+
+    /// Formats a pan position of this range as a string, such as `"C"` for
+    /// the center, `"L50"` for halfway left, or `"R100"` for hard right.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::{PanLaw, PanRange};
+    ///
+    /// let range = PanRange::new(PanLaw::ConstantPower);
+    ///
+    /// assert_eq!(range.format_value(0.0), "C");
+    /// assert_eq!(range.format_value(-0.5), "L50");
+    /// assert_eq!(range.format_value(1.0), "R100");
+    /// ```
+    pub fn format_value(&self, value: f32) -> String {
+        let percent = (value * 100.0).round();
+
+        if percent < 0.0 {
+            format!("L{}", -percent)
+        } else if percent > 0.0 {
+            format!("R{}", percent)
+        } else {
+            String::from("C")
+        }
+    }
+}
+
 /// Returns the corresponding frequency for the whole 10 octave spectrum
 /// (between 20 Hz and 20480 Hz)
 #[inline]
@@ -0,0 +1,26 @@
+//! `iced_graphics` renderer for a keyboard focus outline
+
+pub use crate::style::focus::*;
+
+use iced_graphics::{Background, Color, Primitive, Rectangle};
+
+/// Draws an outline around the bounds of a widget that has keyboard focus.
+///
+/// * bounds - The bounds of the widget.
+/// * style - The focus outline style.
+pub fn draw_focus_outline(bounds: &Rectangle, style: &Style) -> Primitive {
+    let offset = f32::from(style.offset);
+
+    Primitive::Quad {
+        bounds: Rectangle {
+            x: (bounds.x - offset).round(),
+            y: (bounds.y - offset).round(),
+            width: (bounds.width + (offset * 2.0)).round(),
+            height: (bounds.height + (offset * 2.0)).round(),
+        },
+        background: Background::Color(Color::TRANSPARENT),
+        border_radius: style.border_radius,
+        border_width: style.border_width,
+        border_color: style.border_color,
+    }
+}
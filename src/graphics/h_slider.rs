@@ -3,16 +3,17 @@
 //! [`Param`]: ../core/param/trait.Param.html
 
 use crate::core::{ModulationRange, Normal};
-use crate::graphics::{text_marks, tick_marks};
+use crate::graphics::{focus, text_marks, tick_marks, tooltip};
 use crate::native::h_slider;
 use iced_graphics::{Backend, Primitive, Renderer};
 use iced_native::{mouse, Background, Color, Point, Rectangle};
 
 pub use crate::native::h_slider::State;
+pub use crate::native::{AccelCurve, DragMode, OverTravel};
 pub use crate::style::h_slider::{
     ClassicHandle, ClassicRail, ClassicStyle, ModRangePlacement, ModRangeStyle,
-    RectBipolarStyle, RectStyle, Style, StyleSheet, TextMarksStyle,
-    TextureStyle, TickMarksStyle,
+    RectBipolarStyle, RectStyle, SegmentedStyle, Style, StyleSheet,
+    TextMarksStyle, TextureStyle, TickMarksStyle,
 };
 
 struct ValueMarkers<'a> {
@@ -38,16 +39,24 @@ pub type HSlider<'a, Message, Backend> =
 impl<B: Backend> h_slider::Renderer for Renderer<B> {
     type Style = Box<dyn StyleSheet>;
 
+    fn handle_width(&self, style_sheet: &Self::Style) -> u16 {
+        style_sheet.active().handle_width()
+    }
+
     fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
         normal: Normal,
         is_dragging: bool,
+        is_focused: bool,
         mod_range_1: Option<&ModulationRange>,
         mod_range_2: Option<&ModulationRange>,
         tick_marks: Option<&tick_marks::Group>,
         text_marks: Option<&text_marks::Group>,
+        tooltip: Option<&str>,
+        end_padding: u16,
+        scale: f32,
         style_sheet: &Self::Style,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
@@ -58,7 +67,8 @@ impl<B: Backend> h_slider::Renderer for Renderer<B> {
             style_sheet.hovered()
         } else {
             style_sheet.active()
-        };
+        }
+        .scaled(scale);
 
         let bounds = Rectangle {
             x: bounds.x.round(),
@@ -67,36 +77,158 @@ impl<B: Backend> h_slider::Renderer for Renderer<B> {
             height: bounds.height.round(),
         };
 
+        // Nothing fits in bounds with no space, such as during the initial
+        // layout, and the value bounds below would be negative.
+        if bounds.width < 1.0 || bounds.height < 1.0 {
+            return (Primitive::None, mouse::Interaction::default());
+        }
+
+        let end_padding = f32::from(end_padding) * scale;
+
         let value_markers = ValueMarkers {
             tick_marks,
             text_marks,
             mod_range_1,
             mod_range_2,
-            tick_marks_style: style_sheet.tick_marks_style(),
-            text_marks_style: style_sheet.text_marks_style(),
-            mod_range_style_1: style_sheet.mod_range_style(),
-            mod_range_style_2: style_sheet.mod_range_style_2(),
+            tick_marks_style: style_sheet
+                .tick_marks_style()
+                .map(|style| style.scaled(scale)),
+            text_marks_style: style_sheet
+                .text_marks_style()
+                .map(|style| style.scaled(scale)),
+            mod_range_style_1: style_sheet
+                .mod_range_style()
+                .map(|style| style.scaled(scale)),
+            mod_range_style_2: style_sheet
+                .mod_range_style_2()
+                .map(|style| style.scaled(scale)),
         };
 
         let primitives = match style {
-            Style::Texture(style) => {
-                draw_texture_style(normal, &bounds, style, &value_markers)
-            }
-            Style::Classic(style) => {
-                draw_classic_style(normal, &bounds, &style, &value_markers)
-            }
-            Style::Rect(style) => {
-                draw_rect_style(normal, &bounds, &style, &value_markers)
-            }
-            Style::RectBipolar(style) => {
-                draw_rect_bipolar_style(normal, &bounds, &style, &value_markers)
+            Style::Texture(style) => draw_texture_style(
+                normal,
+                &bounds,
+                style,
+                end_padding,
+                &value_markers,
+            ),
+            Style::Classic(style) => draw_classic_style(
+                normal,
+                &bounds,
+                &style,
+                end_padding,
+                &value_markers,
+            ),
+            Style::Rect(style) => draw_rect_style(
+                normal,
+                &bounds,
+                &style,
+                end_padding,
+                &value_markers,
+            ),
+            Style::RectBipolar(style) => draw_rect_bipolar_style(
+                normal,
+                &bounds,
+                &style,
+                end_padding,
+                &value_markers,
+            ),
+            Style::Segmented(style) => {
+                draw_segmented_style(normal, &bounds, &style, &value_markers)
             }
         };
 
+        let focus_style =
+            style_sheet.focus_style().map(|style| style.scaled(scale));
+
+        let primitives = match (is_focused, focus_style) {
+            (true, Some(focus_style)) => Primitive::Group {
+                primitives: vec![
+                    primitives,
+                    focus::draw_focus_outline(&bounds, &focus_style),
+                ],
+            },
+            _ => primitives,
+        };
+
+        let tooltip_style =
+            style_sheet.tooltip_style().map(|style| style.scaled(scale));
+
+        let primitives = match (tooltip, tooltip_style) {
+            (Some(tooltip), Some(tooltip_style)) => Primitive::Group {
+                primitives: vec![
+                    primitives,
+                    tooltip::draw_tooltip(
+                        cursor_position,
+                        tooltip,
+                        &tooltip_style,
+                    ),
+                ],
+            },
+            _ => primitives,
+        };
+
         (primitives, mouse::Interaction::default())
     }
 }
 
+/// Returns the bounds along which the center of the handle of an
+/// [`HSlider`] travels, inset by `inset` pixels from each end of `bounds`.
+///
+/// A value of `0.0` places the center of the handle at the left edge of
+/// the returned bounds, and a value of `1.0` at the right edge. Use
+/// [`handle_x`] to place the handle itself.
+///
+/// [`HSlider`]: type.HSlider.html
+/// [`handle_x`]: fn.handle_x.html
+pub fn value_bounds(bounds: &Rectangle, inset: f32) -> Rectangle {
+    Rectangle {
+        x: bounds.x + inset,
+        y: bounds.y,
+        width: (bounds.width - (inset * 2.0)).max(0.0),
+        height: bounds.height,
+    }
+}
+
+/// Returns the x position of the left edge of a handle `width` pixels wide
+/// whose center is placed at `normal` along the `value_bounds` of an
+/// [`HSlider`], rounded to the nearest pixel.
+///
+/// The position is rounded only once, so a handle placed in bounds from
+/// [`value_bounds`] reaches the same distance from both ends of the
+/// [`HSlider`], including when its size is an odd number of pixels.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::{h_slider, Normal};
+/// use iced_native::Rectangle;
+///
+/// let bounds = Rectangle {
+///     x: 10.0,
+///     y: 0.0,
+///     width: 200.0,
+///     height: 20.0,
+/// };
+///
+/// // The handle is 7 pixels wide, with 4 pixels of end padding
+/// let value_bounds = h_slider::value_bounds(&bounds, 3.5 + 4.0);
+///
+/// // At `0.0` the left edge of the handle is 4 pixels from the left end
+/// let min_x = h_slider::handle_x(&value_bounds, Normal::min(), 7.0);
+/// assert_eq!(min_x - bounds.x, 4.0);
+///
+/// // At `1.0` the right edge of the handle is 4 pixels from the right end
+/// let max_x = h_slider::handle_x(&value_bounds, Normal::max(), 7.0);
+/// assert_eq!(bounds.x + bounds.width - (max_x + 7.0), 4.0);
+/// ```
+///
+/// [`value_bounds`]: fn.value_bounds.html
+/// [`HSlider`]: type.HSlider.html
+pub fn handle_x(value_bounds: &Rectangle, normal: Normal, width: f32) -> f32 {
+    (value_bounds.x + normal.scale(value_bounds.width) - (width / 2.0)).round()
+}
+
 fn draw_value_markers<'a>(
     mark_bounds: &Rectangle,
     mod_bounds: &Rectangle,
@@ -237,18 +369,17 @@ fn draw_mod_range(
                     let start_offset = bounds.width * start;
                     let filled_width = (bounds.width * end) - start_offset;
 
-                    Primitive::Quad {
-                        bounds: Rectangle {
+                    draw_fill(
+                        Rectangle {
                             x: bounds.x + start_offset,
                             y,
                             width: filled_width,
                             height,
                         },
-                        background: Background::Color(color),
-                        border_radius: style.back_border_radius,
-                        border_width: style.back_border_width,
-                        border_color: Color::TRANSPARENT,
-                    }
+                        color,
+                        style.back_border_radius,
+                        style.back_border_width,
+                    )
                 } else {
                     Primitive::None
                 }
@@ -269,14 +400,13 @@ fn draw_texture_style<'a>(
     normal: Normal,
     bounds: &Rectangle,
     style: TextureStyle,
+    end_padding: f32,
     value_markers: &ValueMarkers<'a>,
 ) -> Primitive {
-    let value_bounds = Rectangle {
-        x: (bounds.x + (f32::from(style.handle_width) / 2.0)).round(),
-        y: bounds.y,
-        width: bounds.width - f32::from(style.handle_width),
-        height: bounds.height,
-    };
+    let value_bounds = value_bounds(
+        bounds,
+        (f32::from(style.handle_width) / 2.0) + end_padding,
+    );
 
     let (tick_marks, text_marks, mod_range_1, mod_range_2) =
         draw_value_markers(&value_bounds, &value_bounds, value_markers);
@@ -313,16 +443,12 @@ fn draw_classic_style<'a>(
     normal: Normal,
     bounds: &Rectangle,
     style: &ClassicStyle,
+    end_padding: f32,
     value_markers: &ValueMarkers<'a>,
 ) -> Primitive {
     let handle_width = f32::from(style.handle.width);
 
-    let value_bounds = Rectangle {
-        x: (bounds.x + (handle_width / 2.0)).round(),
-        y: bounds.y,
-        width: bounds.width - handle_width,
-        height: bounds.height,
-    };
+    let value_bounds = value_bounds(bounds, (handle_width / 2.0) + end_padding);
 
     let (tick_marks, text_marks, mod_range_1, mod_range_2) =
         draw_value_markers(&value_bounds, &value_bounds, value_markers);
@@ -330,7 +456,8 @@ fn draw_classic_style<'a>(
     let (top_rail, bottom_rail) = draw_classic_rail(&bounds, &style.rail);
 
     let handle_border_radius = style.handle.border_radius;
-    let handle_offset = normal.scale(value_bounds.width).round();
+    let handle_offset =
+        handle_x(&value_bounds, normal, handle_width) - bounds.x;
     let notch_width = f32::from(style.handle.notch_width);
 
     let handle = Primitive::Quad {
@@ -383,16 +510,16 @@ fn draw_rect_style<'a>(
     normal: Normal,
     bounds: &Rectangle,
     style: &RectStyle,
+    end_padding: f32,
     value_markers: &ValueMarkers<'a>,
 ) -> Primitive {
     let handle_width = f32::from(style.handle_width);
 
-    let value_bounds = Rectangle {
-        x: (bounds.x + (handle_width / 2.0)).round(),
-        y: bounds.y,
-        width: bounds.width - handle_width,
-        height: bounds.height,
-    };
+    let border_width = f32::from(style.back_border_width);
+    let twice_border_width = border_width * 2.0;
+
+    let value_bounds =
+        value_bounds(bounds, (handle_width / 2.0) + border_width + end_padding);
 
     let (tick_marks, text_marks, mod_range_1, mod_range_2) =
         draw_value_markers(&value_bounds, &bounds, value_markers);
@@ -410,26 +537,22 @@ fn draw_rect_style<'a>(
         border_color: style.back_border_color,
     };
 
-    let border_width = f32::from(style.back_border_width);
-    let twice_border_width = border_width * 2.0;
-
-    let handle_offset = normal
-        .scale(value_bounds.width - twice_border_width)
-        .round();
+    let handle_offset =
+        handle_x(&value_bounds, normal, handle_width + twice_border_width)
+            - bounds.x;
 
-    let filled_rect = Primitive::Quad {
-        bounds: Rectangle {
+    let filled_rect = draw_fill(
+        Rectangle {
             x: bounds.x,
             y: bounds.y,
             width: handle_offset + twice_border_width
                 - f32::from(style.handle_filled_gap),
             height: bounds.height,
         },
-        background: Background::Color(style.filled_color),
-        border_radius: style.back_border_radius,
-        border_width: style.back_border_width,
-        border_color: Color::TRANSPARENT,
-    };
+        style.filled_color,
+        style.back_border_radius,
+        style.back_border_width,
+    );
 
     let handle = Primitive::Quad {
         bounds: Rectangle {
@@ -461,23 +584,20 @@ fn draw_rect_bipolar_style<'a>(
     normal: Normal,
     bounds: &Rectangle,
     style: &RectBipolarStyle,
+    end_padding: f32,
     value_markers: &ValueMarkers<'a>,
 ) -> Primitive {
     let handle_width = f32::from(style.handle_width);
 
-    let value_bounds = Rectangle {
-        x: (bounds.x + (handle_width / 2.0)).round(),
-        y: bounds.y,
-        width: bounds.width - handle_width,
-        height: bounds.height,
-    };
+    let border_width = f32::from(style.back_border_width);
+    let twice_border_width = border_width * 2.0;
+
+    let value_bounds =
+        value_bounds(bounds, (handle_width / 2.0) + border_width + end_padding);
 
     let (tick_marks, text_marks, mod_range_1, mod_range_2) =
         draw_value_markers(&value_bounds, &bounds, value_markers);
 
-    let border_width = f32::from(style.back_border_width);
-    let twice_border_width = border_width * 2.0;
-
     let empty_rect = Primitive::Quad {
         bounds: Rectangle {
             x: bounds.x,
@@ -491,9 +611,9 @@ fn draw_rect_bipolar_style<'a>(
         border_color: style.back_border_color,
     };
 
-    let handle_offset = normal
-        .scale(value_bounds.width - twice_border_width)
-        .round();
+    let handle_offset =
+        handle_x(&value_bounds, normal, handle_width + twice_border_width)
+            - bounds.x;
 
     let (handle_color, filled_rect) = if normal.as_f32() > 0.499
         && normal.as_f32() < 0.501
@@ -504,8 +624,8 @@ fn draw_rect_bipolar_style<'a>(
             handle_offset + handle_width + f32::from(style.handle_filled_gap);
         (
             style.handle_left_color,
-            Primitive::Quad {
-                bounds: Rectangle {
+            draw_fill(
+                Rectangle {
                     x: bounds.x + filled_rect_offset,
                     y: bounds.y,
                     width: ((bounds.width / 2.0) - filled_rect_offset
@@ -513,18 +633,17 @@ fn draw_rect_bipolar_style<'a>(
                         .round(),
                     height: bounds.height,
                 },
-                background: Background::Color(style.left_filled_color),
-                border_radius: style.back_border_radius,
-                border_width: style.back_border_width,
-                border_color: Color::TRANSPARENT,
-            },
+                style.left_filled_color,
+                style.back_border_radius,
+                style.back_border_width,
+            ),
         )
     } else {
         let filled_rect_offset = (bounds.width / 2.0).round() - border_width;
         (
             style.handle_right_color,
-            Primitive::Quad {
-                bounds: Rectangle {
+            draw_fill(
+                Rectangle {
                     x: bounds.x + filled_rect_offset,
                     y: bounds.y,
                     width: handle_offset - filled_rect_offset
@@ -532,11 +651,10 @@ fn draw_rect_bipolar_style<'a>(
                         - f32::from(style.handle_filled_gap),
                     height: bounds.height,
                 },
-                background: Background::Color(style.right_filled_color),
-                border_radius: style.back_border_radius,
-                border_width: style.back_border_width,
-                border_color: Color::TRANSPARENT,
-            },
+                style.right_filled_color,
+                style.back_border_radius,
+                style.back_border_width,
+            ),
         )
     };
 
@@ -566,6 +684,86 @@ fn draw_rect_bipolar_style<'a>(
     }
 }
 
+fn draw_segmented_style<'a>(
+    normal: Normal,
+    bounds: &Rectangle,
+    style: &SegmentedStyle,
+    value_markers: &ValueMarkers<'a>,
+) -> Primitive {
+    let border_width = f32::from(style.back_border_width);
+    let twice_border_width = border_width * 2.0;
+
+    let segment_count = style.segment_count.max(1);
+    let segment_gap = f32::from(style.segment_gap);
+
+    let inner_bounds = Rectangle {
+        x: bounds.x + border_width,
+        y: bounds.y + border_width,
+        width: bounds.width - twice_border_width,
+        height: bounds.height - twice_border_width,
+    };
+
+    let segment_width = ((inner_bounds.width
+        - (segment_gap * f32::from(segment_count - 1)))
+        / f32::from(segment_count))
+    .max(0.0);
+
+    let value_bounds = Rectangle {
+        x: (inner_bounds.x + (segment_width / 2.0)).round(),
+        y: bounds.y,
+        width: inner_bounds.width - segment_width,
+        height: bounds.height,
+    };
+
+    let (tick_marks, text_marks, mod_range_1, mod_range_2) =
+        draw_value_markers(&value_bounds, &bounds, value_markers);
+
+    let back_rect = Primitive::Quad {
+        bounds: *bounds,
+        background: Background::Color(style.back_color),
+        border_radius: style.back_border_radius,
+        border_width: style.back_border_width,
+        border_color: style.back_border_color,
+    };
+
+    let lit_count = normal.scale(f32::from(segment_count)).round() as u16;
+
+    let mut primitives: Vec<Primitive> =
+        Vec::with_capacity(usize::from(segment_count) + 5);
+
+    primitives.push(back_rect);
+    primitives.push(tick_marks);
+    primitives.push(text_marks);
+
+    for i in 0..segment_count {
+        let color = if i < lit_count {
+            style.lit_color
+        } else {
+            style.unlit_color
+        };
+
+        primitives.push(Primitive::Quad {
+            bounds: Rectangle {
+                x: (inner_bounds.x
+                    + (f32::from(i) * (segment_width + segment_gap)))
+                    .round(),
+                y: inner_bounds.y,
+                width: segment_width,
+                height: inner_bounds.height,
+            },
+            background: Background::Color(color),
+            border_radius: style.segment_border_radius,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        });
+    }
+
+    primitives.push(mod_range_1);
+    primitives.push(mod_range_2);
+
+    Primitive::Group { primitives }
+}
+
 fn draw_classic_rail(
     bounds: &Rectangle,
     style: &ClassicRail,
@@ -610,3 +808,23 @@ fn draw_classic_rail(
         },
     )
 }
+
+/// Draws the filled portion of a value bar. The border radius is clamped to
+/// half of the smaller dimension of the bounds so that the corners of thin
+/// fills keep their rounded shape.
+fn draw_fill(
+    bounds: Rectangle,
+    color: Color,
+    border_radius: u16,
+    border_width: u16,
+) -> Primitive {
+    let max_radius = (bounds.width.min(bounds.height) / 2.0).max(0.0);
+
+    Primitive::Quad {
+        bounds,
+        background: Background::Color(color),
+        border_radius: border_radius.min(max_radius as u16),
+        border_width,
+        border_color: Color::TRANSPARENT,
+    }
+}
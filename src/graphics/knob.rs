@@ -3,17 +3,22 @@
 //! [`Param`]: ../core/param/struct.Param.html
 
 use crate::core::{ModulationRange, Normal};
-use crate::graphics::{text_marks, tick_marks};
+use crate::graphics::{focus, text_marks, tick_marks, tooltip};
 use crate::native::knob;
 use iced_graphics::canvas::{path::Arc, Frame, Path, Stroke};
 use iced_graphics::{Backend, Primitive, Renderer};
-use iced_native::{mouse, Background, Point, Rectangle, Size, Vector};
+use iced_native::{
+    mouse, Background, Color, HorizontalAlignment, Point, Rectangle, Size,
+    Vector, VerticalAlignment,
+};
 
 pub use crate::native::knob::State;
+pub use crate::native::AccelCurve;
 pub use crate::style::knob::{
-    ArcBipolarStyle, ArcStyle, CircleNotch, CircleStyle, LineCap, LineNotch,
-    ModRangeArcStyle, NotchShape, Style, StyleLength, StyleSheet,
-    TextMarksStyle, TickMarksStyle, ValueArcStyle,
+    ArcBipolarStyle, ArcFillMode, ArcStyle, CenterTextStyle, CircleNotch,
+    CircleStyle, LineCap, LineNotch, ModRangeArcStyle, NotchShape, Style,
+    StyleLength, StyleSheet, TextMarksStyle, TextureNotch, TickMarksStyle,
+    ValueArcStyle,
 };
 
 struct ValueMarkers<'a> {
@@ -52,10 +57,14 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
         cursor_position: Point,
         normal: Normal,
         is_dragging: bool,
+        is_focused: bool,
+        endless: bool,
         mod_range_1: Option<&ModulationRange>,
         mod_range_2: Option<&ModulationRange>,
         tick_marks: Option<&tick_marks::Group>,
         text_marks: Option<&text_marks::Group>,
+        value_text: Option<&str>,
+        tooltip: Option<&str>,
         style_sheet: &Self::Style,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
@@ -77,7 +86,11 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
             mod_range_2,
             tick_marks_style: style_sheet.tick_marks_style(),
             text_marks_style: style_sheet.text_marks_style(),
-            value_arc_style: style_sheet.value_arc_style(),
+            value_arc_style: if endless {
+                None
+            } else {
+                style_sheet.value_arc_style()
+            },
             mod_range_style_1: style_sheet.mod_range_arc_style(),
             mod_range_style_2: style_sheet.mod_range_arc_style_2(),
         };
@@ -113,13 +126,18 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
 
         let radius = bounds.width / 2.0;
 
-        let start_angle =
-            if angle_range.min() >= crate::core::math::THREE_HALVES_PI {
-                angle_range.min() - crate::core::math::THREE_HALVES_PI
-            } else {
-                angle_range.min() + std::f32::consts::FRAC_PI_2
-            };
-        let angle_span = angle_range.max() - angle_range.min();
+        let (start_angle, angle_span) = if endless {
+            (std::f32::consts::FRAC_PI_2, crate::core::math::TWO_PI)
+        } else {
+            let start_angle =
+                if angle_range.min() >= crate::core::math::THREE_HALVES_PI {
+                    angle_range.min() - crate::core::math::THREE_HALVES_PI
+                } else {
+                    angle_range.min() + std::f32::consts::FRAC_PI_2
+                };
+
+            (start_angle, angle_range.max() - angle_range.min())
+        };
         let value_angle = start_angle + (normal.scale(angle_span));
 
         let knob_info = KnobInfo {
@@ -131,20 +149,86 @@ impl<B: Backend> knob::Renderer for Renderer<B> {
             value_angle,
         };
 
-        (
-            match style {
-                Style::Circle(style) => {
-                    draw_circle_style(&knob_info, style, &value_markers)
-                }
-                Style::Arc(style) => {
-                    draw_arc_style(&knob_info, style, &value_markers)
-                }
-                Style::ArcBipolar(style) => {
-                    draw_arc_bipolar_style(&knob_info, style, &value_markers)
-                }
+        let primitives = match style {
+            Style::Circle(style) => {
+                draw_circle_style(&knob_info, style, &value_markers)
+            }
+            Style::Arc(style) => {
+                draw_arc_style(&knob_info, style, &value_markers)
+            }
+            Style::ArcBipolar(style) => {
+                draw_arc_bipolar_style(&knob_info, style, &value_markers)
+            }
+        };
+
+        let primitives = match (value_text, style_sheet.center_text_style()) {
+            (Some(value_text), Some(center_text_style)) => Primitive::Group {
+                primitives: vec![
+                    primitives,
+                    draw_center_text(&bounds, value_text, &center_text_style),
+                ],
+            },
+            _ => primitives,
+        };
+
+        let primitives = match (is_focused, style_sheet.focus_style()) {
+            (true, Some(focus_style)) => Primitive::Group {
+                primitives: vec![
+                    primitives,
+                    focus::draw_focus_outline(&bounds, &focus_style),
+                ],
             },
-            mouse::Interaction::default(),
-        )
+            _ => primitives,
+        };
+
+        let primitives = match (tooltip, style_sheet.tooltip_style()) {
+            (Some(tooltip), Some(tooltip_style)) => Primitive::Group {
+                primitives: vec![
+                    primitives,
+                    tooltip::draw_tooltip(
+                        cursor_position,
+                        tooltip,
+                        &tooltip_style,
+                    ),
+                ],
+            },
+            _ => primitives,
+        };
+
+        (primitives, mouse::Interaction::default())
+    }
+}
+
+fn draw_center_text(
+    bounds: &Rectangle,
+    text: &str,
+    style: &CenterTextStyle,
+) -> Primitive {
+    let char_count = text.chars().count().max(1) as f32;
+    let fit_size = bounds.width / (char_count * style.char_width);
+
+    // Shrink text that would overflow the knob, down to the minimum size.
+    let text_size = f32::from(style.text_size)
+        .min(fit_size)
+        .max(f32::from(style.min_text_size));
+
+    Primitive::Clip {
+        bounds: *bounds,
+        offset: Vector::new(0, 0),
+        content: Box::new(Primitive::Text {
+            content: String::from(text),
+            size: text_size,
+            bounds: Rectangle {
+                x: bounds.center_x(),
+                y: bounds.center_y(),
+                width: bounds.width,
+                height: bounds.height,
+            },
+            color: style.color,
+            font: style.font,
+            horizontal_alignment: HorizontalAlignment::Center,
+            vertical_alignment: VerticalAlignment::Center,
+        }),
     }
 }
 
@@ -267,7 +351,14 @@ fn draw_value_arc(
             frame.stroke(&empty_path, empty_stroke);
         }
 
-        if let Some(right_filled_color) = style.right_filled_color {
+        let right_filled_color = match style.fill_mode {
+            ArcFillMode::FromCenter => Some(
+                style.right_filled_color.unwrap_or(style.left_filled_color),
+            ),
+            ArcFillMode::FromStart => None,
+        };
+
+        if let Some(right_filled_color) = right_filled_color {
             if knob_info.value.as_f32() < 0.499
                 || knob_info.value.as_f32() > 0.501
             {
@@ -501,11 +592,40 @@ fn draw_line_notch(knob_info: &KnobInfo, style: &LineNotch) -> Primitive {
     }
 }
 
+fn draw_texture_notch(knob_info: &KnobInfo, style: &TextureNotch) -> Primitive {
+    let value_angle = knob_info.value_angle + std::f32::consts::FRAC_PI_2;
+
+    let (dx, dy) = if value_angle < -0.001 || value_angle > 0.001 {
+        value_angle.sin_cos()
+    } else {
+        (0.0, -1.0)
+    };
+
+    let offset_radius = knob_info.radius
+        - style.offset.from_knob_diameter(knob_info.bounds.width);
+
+    Primitive::Image {
+        handle: style.image_handle.clone(),
+        bounds: Rectangle {
+            x: (knob_info.bounds.center_x()
+                + (dx * offset_radius)
+                + style.image_bounds.x)
+                .round(),
+            y: (knob_info.bounds.center_y() - (dy * offset_radius)
+                + style.image_bounds.y)
+                .round(),
+            width: style.image_bounds.width,
+            height: style.image_bounds.height,
+        },
+    }
+}
+
 fn draw_notch(knob_info: &KnobInfo, notch: &NotchShape) -> Primitive {
     match notch {
         NotchShape::None => Primitive::None,
         NotchShape::Circle(style) => draw_circle_notch(knob_info, style),
         NotchShape::Line(style) => draw_line_notch(knob_info, style),
+        NotchShape::Texture(style) => draw_texture_notch(knob_info, style),
     }
 }
 
@@ -517,15 +637,56 @@ fn draw_circle_style<'a>(
     let (tick_marks, text_marks, value_arc, mod_range_arc_1, mod_range_arc_2) =
         draw_value_markers(knob_info, value_markers);
 
+    let mut rings: Vec<Primitive> = Vec::with_capacity(style.rings.len());
+    let mut ring_inset = 0.0;
+
+    for (width, color) in style.rings.iter() {
+        let radius = knob_info.radius - ring_inset;
+        if radius <= 0.0 {
+            break;
+        }
+
+        rings.push(Primitive::Quad {
+            bounds: Rectangle {
+                x: knob_info.bounds.x + ring_inset,
+                y: knob_info.bounds.y + ring_inset,
+                width: radius * 2.0,
+                height: radius * 2.0,
+            },
+            background: Background::Color(*color),
+            border_radius: radius as u16,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        });
+
+        ring_inset += f32::from(*width);
+    }
+
+    let inner_radius = (knob_info.radius - ring_inset).max(0.0);
+
+    let inner_knob_info = KnobInfo {
+        bounds: Rectangle {
+            x: knob_info.bounds.x + ring_inset,
+            y: knob_info.bounds.y + ring_inset,
+            width: inner_radius * 2.0,
+            height: inner_radius * 2.0,
+        },
+        start_angle: knob_info.start_angle,
+        angle_span: knob_info.angle_span,
+        radius: inner_radius,
+        value: knob_info.value,
+        value_angle: knob_info.value_angle,
+    };
+
     let knob_back = Primitive::Quad {
-        bounds: knob_info.bounds,
+        bounds: inner_knob_info.bounds,
         background: Background::Color(style.color),
-        border_radius: knob_info.radius as u16,
+        border_radius: inner_knob_info.radius as u16,
         border_width: style.border_width,
         border_color: style.border_color,
     };
 
-    let notch = draw_notch(knob_info, &style.notch);
+    let notch = draw_notch(&inner_knob_info, &style.notch);
 
     Primitive::Group {
         primitives: vec![
@@ -534,6 +695,7 @@ fn draw_circle_style<'a>(
             value_arc,
             mod_range_arc_1,
             mod_range_arc_2,
+            Primitive::Group { primitives: rings },
             knob_back,
             notch,
         ],
@@ -4,11 +4,15 @@ pub mod h_slider;
 pub mod knob;
 pub mod mod_range_input;
 pub mod ramp;
+pub mod segment_display;
 pub mod v_slider;
+pub mod value_marks;
 pub mod xy_pad;
 
+pub mod focus;
 pub mod text_marks;
 pub mod tick_marks;
+pub mod tooltip;
 
 //pub mod db_meter;
 //pub mod phase_meter;
@@ -2,14 +2,17 @@
 //!
 //! [`Param`]: ../core/param/struct.Param.html
 
+use crate::core::Normal;
 use crate::native::mod_range_input;
 
 use iced_graphics::{Backend, Primitive, Renderer};
-use iced_native::{mouse, Background, Point, Rectangle};
+use iced_native::{mouse, Background, Color, Point, Rectangle};
 
 pub use crate::native::mod_range_input::State;
+pub use crate::native::DragAxis;
 pub use crate::style::mod_range_input::{
     CircleStyle, DefaultInvisible, SquareStyle, Style, StyleSheet,
+    VerticalBarStyle,
 };
 
 /// An interactive dot that controls an [`Param`]
@@ -25,6 +28,7 @@ impl<B: Backend> mod_range_input::Renderer for Renderer<B> {
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
+        normal: Normal,
         is_dragging: bool,
         style_sheet: &Self::Style,
     ) -> Self::Output {
@@ -77,9 +81,83 @@ impl<B: Backend> mod_range_input::Renderer for Renderer<B> {
                     border_color: style.border_color,
                 }
             }
+            Style::VerticalBar(style) => {
+                draw_vertical_bar_style(&bounds, normal, &style)
+            }
             Style::Invisible => Primitive::None,
         };
 
         (dot, mouse::Interaction::default())
     }
 }
+
+fn draw_vertical_bar_style(
+    bounds: &Rectangle,
+    normal: Normal,
+    style: &VerticalBarStyle,
+) -> Primitive {
+    let width = f32::from(style.width).min(bounds.width);
+    let bar_bounds = Rectangle {
+        x: (bounds.center_x() - (width / 2.0)).round(),
+        y: bounds.y.round(),
+        width,
+        height: bounds.height.round(),
+    };
+
+    let back = Primitive::Quad {
+        bounds: bar_bounds,
+        background: Background::Color(style.back_color),
+        border_radius: style.back_border_radius,
+        border_width: style.back_border_width,
+        border_color: style.back_border_color,
+    };
+
+    let border_width = f32::from(style.back_border_width);
+    let half_height = (bar_bounds.height / 2.0) - border_width;
+    let center_y = bar_bounds.center_y().round();
+    let depth = (normal.as_f32() * 2.0) - 1.0;
+    let fill_height = (depth.abs() * half_height).round();
+
+    let fill = if fill_height < 1.0 {
+        Primitive::None
+    } else {
+        let (y, color) = if depth > 0.0 {
+            (center_y - fill_height, style.positive_color)
+        } else {
+            (center_y, style.negative_color)
+        };
+
+        Primitive::Quad {
+            bounds: Rectangle {
+                x: bar_bounds.x + border_width,
+                y,
+                width: bar_bounds.width - (border_width * 2.0),
+                height: fill_height,
+            },
+            background: Background::Color(color),
+            border_radius: 0,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        }
+    };
+
+    let center_line = match style.center_line_color {
+        Some(color) => Primitive::Quad {
+            bounds: Rectangle {
+                x: bar_bounds.x,
+                y: center_y,
+                width: bar_bounds.width,
+                height: 1.0,
+            },
+            background: Background::Color(color),
+            border_radius: 0,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        },
+        None => Primitive::None,
+    };
+
+    Primitive::Group {
+        primitives: vec![back, fill, center_line],
+    }
+}
@@ -9,7 +9,7 @@ use iced_graphics::canvas::{Frame, LineCap, Path, Stroke};
 use iced_graphics::{Backend, Primitive, Renderer};
 use iced_native::{mouse, Background, Point, Rectangle, Size, Vector};
 
-pub use crate::native::ramp::{RampDirection, State};
+pub use crate::native::ramp::{RampCurve, RampDirection, State};
 pub use crate::style::ramp::{Style, StyleSheet};
 
 /// A ramp GUI widget that controls a [`Param`]. It is usually used to
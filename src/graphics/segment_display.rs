@@ -0,0 +1,276 @@
+//! Display text such as the formatted value of a parameter as a
+//! seven-segment-style readout
+
+use crate::native::segment_display;
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Background, Color, Rectangle};
+
+pub use crate::style::segment_display::{Style, StyleSheet};
+
+const SEG_A: u8 = 0b000_0001;
+const SEG_B: u8 = 0b000_0010;
+const SEG_C: u8 = 0b000_0100;
+const SEG_D: u8 = 0b000_1000;
+const SEG_E: u8 = 0b001_0000;
+const SEG_F: u8 = 0b010_0000;
+const SEG_G: u8 = 0b100_0000;
+
+/// A seven-segment-style readout that displays text such as the formatted
+/// value of a parameter.
+pub type SegmentDisplay<Backend> =
+    segment_display::SegmentDisplay<Renderer<Backend>>;
+
+impl<B: Backend> segment_display::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        text: &str,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let back = Primitive::Quad {
+            bounds,
+            background: Background::Color(style.back_color),
+            border_radius: style.back_border_radius,
+            border_width: style.back_border_width,
+            border_color: style.back_border_color,
+        };
+
+        let digits = parse_digits(text);
+        let style = fit_to_bounds(style, &bounds, digits.len());
+
+        let digit_width = f32::from(style.digit_width);
+        let digit_height = f32::from(style.digit_height);
+        let digit_spacing = f32::from(style.digit_spacing);
+
+        let mut primitives: Vec<Primitive> =
+            Vec::with_capacity(1 + (digits.len() * 8));
+        primitives.push(back);
+
+        let y = (bounds.center_y() - (digit_height / 2.0)).round();
+        let mut x = bounds.x + bounds.width
+            - f32::from(style.padding)
+            - (digits.len() as f32 * (digit_width + digit_spacing))
+            + digit_spacing;
+
+        for (segments, decimal_point) in digits {
+            draw_digit(&mut primitives, x, y, segments, decimal_point, &style);
+
+            x += digit_width + digit_spacing;
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+/// Shrinks the digits of the style so that `digit_count` digits fit inside
+/// the padded bounds. The style is unchanged if they already fit.
+fn fit_to_bounds(
+    style: Style,
+    bounds: &Rectangle,
+    digit_count: usize,
+) -> Style {
+    if digit_count == 0 {
+        return style;
+    }
+
+    let padding = f32::from(style.padding) * 2.0;
+    let digit_width = f32::from(style.digit_width);
+    let digit_height = f32::from(style.digit_height);
+    let digit_spacing = f32::from(style.digit_spacing);
+
+    let text_width =
+        (digit_count as f32 * (digit_width + digit_spacing)) - digit_spacing;
+
+    let fit = ((bounds.width - padding) / text_width)
+        .min((bounds.height - padding) / digit_height);
+
+    if !(fit < 1.0) {
+        return style;
+    }
+
+    let fit = fit.max(0.0);
+    let shrink = |value: f32| (value * fit).floor() as u16;
+
+    Style {
+        digit_width: shrink(digit_width),
+        digit_height: shrink(digit_height),
+        digit_spacing: shrink(digit_spacing),
+        segment_thickness: shrink(f32::from(style.segment_thickness)).max(1),
+        ..style
+    }
+}
+
+/// Returns the lit segments of each digit in `text`, and whether its decimal
+/// point is lit.
+fn parse_digits(text: &str) -> Vec<(u8, bool)> {
+    let mut digits: Vec<(u8, bool)> = Vec::with_capacity(text.len());
+
+    for c in text.chars() {
+        if c == '.' {
+            match digits.last_mut() {
+                Some((_, decimal_point)) if !*decimal_point => {
+                    *decimal_point = true;
+                }
+                _ => digits.push((0, true)),
+            }
+        } else {
+            digits.push((char_segments(c), false));
+        }
+    }
+
+    digits
+}
+
+fn char_segments(c: char) -> u8 {
+    match c {
+        '0' | 'O' => SEG_A | SEG_B | SEG_C | SEG_D | SEG_E | SEG_F,
+        '1' => SEG_B | SEG_C,
+        '2' => SEG_A | SEG_B | SEG_D | SEG_E | SEG_G,
+        '3' => SEG_A | SEG_B | SEG_C | SEG_D | SEG_G,
+        '4' => SEG_B | SEG_C | SEG_F | SEG_G,
+        '5' | 'S' | 's' => SEG_A | SEG_C | SEG_D | SEG_F | SEG_G,
+        '6' => SEG_A | SEG_C | SEG_D | SEG_E | SEG_F | SEG_G,
+        '7' => SEG_A | SEG_B | SEG_C,
+        '8' => SEG_A | SEG_B | SEG_C | SEG_D | SEG_E | SEG_F | SEG_G,
+        '9' => SEG_A | SEG_B | SEG_C | SEG_D | SEG_F | SEG_G,
+        '-' => SEG_G,
+        '_' => SEG_D,
+        'A' | 'a' => SEG_A | SEG_B | SEG_C | SEG_E | SEG_F | SEG_G,
+        'B' | 'b' => SEG_C | SEG_D | SEG_E | SEG_F | SEG_G,
+        'C' => SEG_A | SEG_D | SEG_E | SEG_F,
+        'c' => SEG_D | SEG_E | SEG_G,
+        'D' | 'd' => SEG_B | SEG_C | SEG_D | SEG_E | SEG_G,
+        'E' | 'e' => SEG_A | SEG_D | SEG_E | SEG_F | SEG_G,
+        'F' | 'f' => SEG_A | SEG_E | SEG_F | SEG_G,
+        'H' => SEG_B | SEG_C | SEG_E | SEG_F | SEG_G,
+        'h' => SEG_C | SEG_E | SEG_F | SEG_G,
+        'I' => SEG_E | SEG_F,
+        'i' => SEG_E,
+        'J' | 'j' => SEG_B | SEG_C | SEG_D | SEG_E,
+        'K' | 'k' => SEG_A | SEG_C | SEG_E | SEG_F | SEG_G,
+        'L' | 'l' => SEG_D | SEG_E | SEG_F,
+        'N' | 'n' => SEG_C | SEG_E | SEG_G,
+        'o' => SEG_C | SEG_D | SEG_E | SEG_G,
+        'P' | 'p' => SEG_A | SEG_B | SEG_E | SEG_F | SEG_G,
+        'R' | 'r' => SEG_E | SEG_G,
+        'T' | 't' => SEG_D | SEG_E | SEG_F | SEG_G,
+        'U' => SEG_B | SEG_C | SEG_D | SEG_E | SEG_F,
+        'u' => SEG_C | SEG_D | SEG_E,
+        'Y' | 'y' => SEG_B | SEG_C | SEG_D | SEG_F | SEG_G,
+        'Z' | 'z' => SEG_A | SEG_B | SEG_D | SEG_E | SEG_G,
+        _ => 0,
+    }
+}
+
+fn draw_digit(
+    primitives: &mut Vec<Primitive>,
+    x: f32,
+    y: f32,
+    segments: u8,
+    decimal_point: bool,
+    style: &Style,
+) {
+    let width = f32::from(style.digit_width);
+    let height = f32::from(style.digit_height);
+    let thickness = f32::from(style.segment_thickness);
+
+    let half_y = (y + (height / 2.0) - (thickness / 2.0)).round();
+    let h_length = width - (thickness * 2.0);
+    let upper_length = half_y - y - thickness;
+    let lower_length = y + height - thickness - half_y - thickness;
+
+    let segment_bounds = [
+        (SEG_A, x + thickness, y, h_length, thickness),
+        (
+            SEG_B,
+            x + width - thickness,
+            y + thickness,
+            thickness,
+            upper_length,
+        ),
+        (
+            SEG_C,
+            x + width - thickness,
+            half_y + thickness,
+            thickness,
+            lower_length,
+        ),
+        (
+            SEG_D,
+            x + thickness,
+            y + height - thickness,
+            h_length,
+            thickness,
+        ),
+        (SEG_E, x, half_y + thickness, thickness, lower_length),
+        (SEG_F, x, y + thickness, thickness, upper_length),
+        (SEG_G, x + thickness, half_y, h_length, thickness),
+    ];
+
+    for (segment, x, y, width, height) in segment_bounds.iter() {
+        let color = if segments & segment != 0 {
+            Some(style.lit_color)
+        } else {
+            style.unlit_color
+        };
+
+        if let Some(color) = color {
+            primitives.push(draw_segment(*x, *y, *width, *height, color));
+        }
+    }
+
+    let decimal_point_color = if decimal_point {
+        Some(style.lit_color)
+    } else {
+        style.unlit_color
+    };
+
+    if let Some(color) = decimal_point_color {
+        let dp_x = (x + width + (f32::from(style.digit_spacing) / 2.0)
+            - (thickness / 2.0))
+            .round();
+
+        primitives.push(draw_segment(
+            dp_x,
+            y + height - thickness,
+            thickness,
+            thickness,
+            color,
+        ));
+    }
+}
+
+fn draw_segment(
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    color: Color,
+) -> Primitive {
+    Primitive::Quad {
+        bounds: Rectangle {
+            x,
+            y,
+            width: width.max(0.0),
+            height: height.max(0.0),
+        },
+        background: Background::Color(color),
+        border_radius: 0,
+        border_width: 0,
+        border_color: Color::TRANSPARENT,
+    }
+}
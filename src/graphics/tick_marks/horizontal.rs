@@ -15,6 +15,7 @@ fn draw_horizontal_lines(
     length: u16,
     color: Color,
     inverse: bool,
+    anti_alias: bool,
 ) {
     let start_x = bounds_x - (f32::from(width) / 2.0);
     let back_color = Background::Color(color);
@@ -23,7 +24,10 @@ fn draw_horizontal_lines(
         for tick_mark in tick_marks {
             primitives.push(Primitive::Quad {
                 bounds: Rectangle {
-                    x: (start_x + tick_mark.scale_inv(bounds_width)).round(),
+                    x: super::snap(
+                        start_x + tick_mark.scale_inv(bounds_width),
+                        anti_alias,
+                    ),
                     y,
                     width: f32::from(width),
                     height: f32::from(length),
@@ -38,7 +42,10 @@ fn draw_horizontal_lines(
         for tick_mark in tick_marks {
             primitives.push(Primitive::Quad {
                 bounds: Rectangle {
-                    x: (start_x + tick_mark.scale(bounds_width)).round(),
+                    x: super::snap(
+                        start_x + tick_mark.scale(bounds_width),
+                        anti_alias,
+                    ),
                     y,
                     width: f32::from(width),
                     height: f32::from(length),
@@ -52,6 +59,53 @@ fn draw_horizontal_lines(
     }
 }
 
+fn draw_horizontal_dashed_lines(
+    primitives: &mut Vec<Primitive>,
+    tick_marks: &[Normal],
+    bounds_x: f32,
+    bounds_width: f32,
+    y: f32,
+    width: u16,
+    length: u16,
+    dash: u16,
+    gap: u16,
+    color: Color,
+    inverse: bool,
+    anti_alias: bool,
+) {
+    let start_x = bounds_x - (f32::from(width) / 2.0);
+    let back_color = Background::Color(color);
+    let length = f32::from(length);
+    let dash = f32::from(dash.max(1));
+    let step = dash + f32::from(gap);
+
+    for tick_mark in tick_marks {
+        let x = if inverse {
+            super::snap(start_x + tick_mark.scale_inv(bounds_width), anti_alias)
+        } else {
+            super::snap(start_x + tick_mark.scale(bounds_width), anti_alias)
+        };
+
+        let mut dash_start = 0.0;
+        while dash_start < length {
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x,
+                    y: y + dash_start,
+                    width: f32::from(width),
+                    height: dash.min(length - dash_start),
+                },
+                background: back_color,
+                border_radius: 0,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            });
+
+            dash_start += step;
+        }
+    }
+}
+
 fn draw_horizontal_circles(
     primitives: &mut Vec<Primitive>,
     tick_marks: &[Normal],
@@ -61,6 +115,7 @@ fn draw_horizontal_circles(
     diameter: u16,
     color: Color,
     inverse: bool,
+    anti_alias: bool,
 ) {
     let diameter = f32::from(diameter);
     let radius = (diameter / 2.0).round() as u16;
@@ -71,7 +126,10 @@ fn draw_horizontal_circles(
         for tick_mark in tick_marks {
             primitives.push(Primitive::Quad {
                 bounds: Rectangle {
-                    x: (start_x + tick_mark.scale_inv(bounds_width)).round(),
+                    x: super::snap(
+                        start_x + tick_mark.scale_inv(bounds_width),
+                        anti_alias,
+                    ),
                     y,
                     width: diameter,
                     height: diameter,
@@ -86,7 +144,10 @@ fn draw_horizontal_circles(
         for tick_mark in tick_marks {
             primitives.push(Primitive::Quad {
                 bounds: Rectangle {
-                    x: (start_x + tick_mark.scale(bounds_width)).round(),
+                    x: super::snap(
+                        start_x + tick_mark.scale(bounds_width),
+                        anti_alias,
+                    ),
                     y,
                     width: diameter,
                     height: diameter,
@@ -108,10 +169,11 @@ fn draw_horizontal_top_aligned_tier(
     tick_marks: Option<&Vec<Normal>>,
     shape: &Shape,
     inverse: bool,
+    anti_alias: bool,
 ) {
     if let Some(tick_marks) = tick_marks {
         match shape {
-            Shape::None => return,
+            Shape::None | Shape::Bar { .. } => return,
             Shape::Line {
                 length,
                 width,
@@ -127,6 +189,29 @@ fn draw_horizontal_top_aligned_tier(
                     *length,
                     *color,
                     inverse,
+                    anti_alias,
+                );
+            }
+            Shape::DashedLine {
+                length,
+                width,
+                dash,
+                gap,
+                color,
+            } => {
+                draw_horizontal_dashed_lines(
+                    primitives,
+                    tick_marks,
+                    bounds.x,
+                    bounds.width,
+                    y,
+                    *width,
+                    *length,
+                    *dash,
+                    *gap,
+                    *color,
+                    inverse,
+                    anti_alias,
                 );
             }
             Shape::Circle { diameter, color } => {
@@ -139,6 +224,7 @@ fn draw_horizontal_top_aligned_tier(
                     *diameter,
                     *color,
                     inverse,
+                    anti_alias,
                 );
             }
         }
@@ -156,26 +242,29 @@ fn draw_horizontal_top_aligned(
     draw_horizontal_top_aligned_tier(
         primitives,
         bounds,
-        y,
+        y + f32::from(style.tier_offsets.tier_1),
         tick_marks.tier_1(),
         &style.tier_1,
         inverse,
+        style.anti_alias,
     );
     draw_horizontal_top_aligned_tier(
         primitives,
         bounds,
-        y,
+        y + f32::from(style.tier_offsets.tier_2),
         tick_marks.tier_2(),
         &style.tier_2,
         inverse,
+        style.anti_alias,
     );
     draw_horizontal_top_aligned_tier(
         primitives,
         bounds,
-        y,
+        y + f32::from(style.tier_offsets.tier_3),
         tick_marks.tier_3(),
         &style.tier_3,
         inverse,
+        style.anti_alias,
     );
 }
 
@@ -187,10 +276,11 @@ fn draw_horizontal_bottom_aligned_tier(
     tick_marks: Option<&Vec<Normal>>,
     shape: &Shape,
     inverse: bool,
+    anti_alias: bool,
 ) {
     if let Some(tick_marks) = tick_marks {
         match shape {
-            Shape::None => return,
+            Shape::None | Shape::Bar { .. } => return,
             Shape::Line {
                 length,
                 width,
@@ -206,6 +296,29 @@ fn draw_horizontal_bottom_aligned_tier(
                     *length,
                     *color,
                     inverse,
+                    anti_alias,
+                );
+            }
+            Shape::DashedLine {
+                length,
+                width,
+                dash,
+                gap,
+                color,
+            } => {
+                draw_horizontal_dashed_lines(
+                    primitives,
+                    tick_marks,
+                    bounds.x,
+                    bounds.width,
+                    y - f32::from(*length),
+                    *width,
+                    *length,
+                    *dash,
+                    *gap,
+                    *color,
+                    inverse,
+                    anti_alias,
                 );
             }
             Shape::Circle { diameter, color } => {
@@ -218,6 +331,7 @@ fn draw_horizontal_bottom_aligned_tier(
                     *diameter,
                     *color,
                     inverse,
+                    anti_alias,
                 );
             }
         }
@@ -235,26 +349,29 @@ fn draw_horizontal_bottom_aligned(
     draw_horizontal_bottom_aligned_tier(
         primitives,
         bounds,
-        y,
+        y - f32::from(style.tier_offsets.tier_1),
         tick_marks.tier_1(),
         &style.tier_1,
         inverse,
+        style.anti_alias,
     );
     draw_horizontal_bottom_aligned_tier(
         primitives,
         bounds,
-        y,
+        y - f32::from(style.tier_offsets.tier_2),
         tick_marks.tier_2(),
         &style.tier_2,
         inverse,
+        style.anti_alias,
     );
     draw_horizontal_bottom_aligned_tier(
         primitives,
         bounds,
-        y,
+        y - f32::from(style.tier_offsets.tier_3),
         tick_marks.tier_3(),
         &style.tier_3,
         inverse,
+        style.anti_alias,
     );
 }
 
@@ -267,10 +384,11 @@ fn draw_horizontal_center_aligned_tier(
     shape: &Shape,
     fill_length: bool,
     inverse: bool,
+    anti_alias: bool,
 ) {
     if let Some(tick_marks) = tick_marks {
         match shape {
-            Shape::None => return,
+            Shape::None | Shape::Bar { .. } => return,
             Shape::Line {
                 length,
                 width,
@@ -282,7 +400,10 @@ fn draw_horizontal_center_aligned_tier(
                         (bounds.height - (f32::from(*length) * 2.0)) as u16,
                     )
                 } else {
-                    ((y - (f32::from(*length) / 2.0)).round(), *length)
+                    (
+                        super::snap(y - (f32::from(*length) / 2.0), anti_alias),
+                        *length,
+                    )
                 };
 
                 draw_horizontal_lines(
@@ -295,6 +416,41 @@ fn draw_horizontal_center_aligned_tier(
                     length,
                     *color,
                     inverse,
+                    anti_alias,
+                );
+            }
+            Shape::DashedLine {
+                length,
+                width,
+                dash,
+                gap,
+                color,
+            } => {
+                let (y, length) = if fill_length {
+                    (
+                        bounds.y + f32::from(*length),
+                        (bounds.height - (f32::from(*length) * 2.0)) as u16,
+                    )
+                } else {
+                    (
+                        super::snap(y - (f32::from(*length) / 2.0), anti_alias),
+                        *length,
+                    )
+                };
+
+                draw_horizontal_dashed_lines(
+                    primitives,
+                    tick_marks,
+                    bounds.x,
+                    bounds.width,
+                    y,
+                    *width,
+                    length,
+                    *dash,
+                    *gap,
+                    *color,
+                    inverse,
+                    anti_alias,
                 );
             }
             Shape::Circle { diameter, color } => {
@@ -304,7 +460,13 @@ fn draw_horizontal_center_aligned_tier(
                         (bounds.height - (f32::from(*diameter) * 2.0)) as u16,
                     )
                 } else {
-                    ((y - (f32::from(*diameter) / 2.0)).round(), *diameter)
+                    (
+                        super::snap(
+                            y - (f32::from(*diameter) / 2.0),
+                            anti_alias,
+                        ),
+                        *diameter,
+                    )
                 };
 
                 draw_horizontal_circles(
@@ -316,6 +478,7 @@ fn draw_horizontal_center_aligned_tier(
                     diameter,
                     *color,
                     inverse,
+                    anti_alias,
                 );
             }
         }
@@ -339,6 +502,7 @@ fn draw_horizontal_center_aligned(
         &style.tier_1,
         fill_length,
         inverse,
+        style.anti_alias,
     );
     draw_horizontal_center_aligned_tier(
         primitives,
@@ -348,6 +512,7 @@ fn draw_horizontal_center_aligned(
         &style.tier_2,
         fill_length,
         inverse,
+        style.anti_alias,
     );
     draw_horizontal_center_aligned_tier(
         primitives,
@@ -357,6 +522,7 @@ fn draw_horizontal_center_aligned(
         &style.tier_3,
         fill_length,
         inverse,
+        style.anti_alias,
     );
 }
 
@@ -370,10 +536,11 @@ fn draw_horizontal_center_aligned_split_tier(
     fill_length: bool,
     gap: f32,
     inverse: bool,
+    anti_alias: bool,
 ) {
     if let Some(tick_marks) = tick_marks {
         match shape {
-            Shape::None => return,
+            Shape::None | Shape::Bar { .. } => return,
             Shape::Line {
                 length,
                 width,
@@ -383,12 +550,21 @@ fn draw_horizontal_center_aligned_split_tier(
                     let length = (f32::from(*length)
                         + ((bounds.height + gap) / 2.0))
                         .round();
-                    ((y - length - (gap / 2.0)).round(), length as u16)
+                    (
+                        super::snap(y - length - (gap / 2.0), anti_alias),
+                        length as u16,
+                    )
                 } else {
-                    ((y - f32::from(*length) - (gap / 2.0)).round(), *length)
+                    (
+                        super::snap(
+                            y - f32::from(*length) - (gap / 2.0),
+                            anti_alias,
+                        ),
+                        *length,
+                    )
                 };
 
-                let right_y = (y + (gap / 2.0)).round();
+                let right_y = super::snap(y + (gap / 2.0), anti_alias);
 
                 draw_horizontal_lines(
                     primitives,
@@ -400,6 +576,7 @@ fn draw_horizontal_center_aligned_split_tier(
                     length,
                     *color,
                     inverse,
+                    anti_alias,
                 );
                 draw_horizontal_lines(
                     primitives,
@@ -411,6 +588,63 @@ fn draw_horizontal_center_aligned_split_tier(
                     length,
                     *color,
                     inverse,
+                    anti_alias,
+                );
+            }
+            Shape::DashedLine {
+                length,
+                width,
+                dash,
+                gap: dash_gap,
+                color,
+            } => {
+                let (left_y, length) = if fill_length {
+                    let length = (f32::from(*length)
+                        + ((bounds.height + gap) / 2.0))
+                        .round();
+                    (
+                        super::snap(y - length - (gap / 2.0), anti_alias),
+                        length as u16,
+                    )
+                } else {
+                    (
+                        super::snap(
+                            y - f32::from(*length) - (gap / 2.0),
+                            anti_alias,
+                        ),
+                        *length,
+                    )
+                };
+
+                let right_y = super::snap(y + (gap / 2.0), anti_alias);
+
+                draw_horizontal_dashed_lines(
+                    primitives,
+                    tick_marks,
+                    bounds.x,
+                    bounds.width,
+                    left_y,
+                    *width,
+                    length,
+                    *dash,
+                    *dash_gap,
+                    *color,
+                    inverse,
+                    anti_alias,
+                );
+                draw_horizontal_dashed_lines(
+                    primitives,
+                    tick_marks,
+                    bounds.x,
+                    bounds.width,
+                    right_y,
+                    *width,
+                    length,
+                    *dash,
+                    *dash_gap,
+                    *color,
+                    inverse,
+                    anti_alias,
                 );
             }
             Shape::Circle { diameter, color } => {
@@ -422,12 +656,15 @@ fn draw_horizontal_center_aligned_split_tier(
                     )
                 } else {
                     (
-                        (y - f32::from(*diameter) - (gap / 2.0)).round(),
+                        super::snap(
+                            y - f32::from(*diameter) - (gap / 2.0),
+                            anti_alias,
+                        ),
                         *diameter,
                     )
                 };
 
-                let right_y = (y + (gap / 2.0)).round();
+                let right_y = super::snap(y + (gap / 2.0), anti_alias);
 
                 draw_horizontal_circles(
                     primitives,
@@ -438,6 +675,7 @@ fn draw_horizontal_center_aligned_split_tier(
                     diameter,
                     *color,
                     inverse,
+                    anti_alias,
                 );
                 draw_horizontal_circles(
                     primitives,
@@ -448,6 +686,7 @@ fn draw_horizontal_center_aligned_split_tier(
                     diameter,
                     *color,
                     inverse,
+                    anti_alias,
                 );
             }
         }
@@ -471,8 +710,9 @@ fn draw_horizontal_center_aligned_split(
         tick_marks.tier_1(),
         &style.tier_1,
         fill_length,
-        gap,
+        gap + (2.0 * f32::from(style.tier_offsets.tier_1)),
         inverse,
+        style.anti_alias,
     );
     draw_horizontal_center_aligned_split_tier(
         primitives,
@@ -481,8 +721,9 @@ fn draw_horizontal_center_aligned_split(
         tick_marks.tier_2(),
         &style.tier_2,
         fill_length,
-        gap,
+        gap + (2.0 * f32::from(style.tier_offsets.tier_2)),
         inverse,
+        style.anti_alias,
     );
     draw_horizontal_center_aligned_split_tier(
         primitives,
@@ -491,8 +732,69 @@ fn draw_horizontal_center_aligned_split(
         tick_marks.tier_3(),
         &style.tier_3,
         fill_length,
-        gap,
+        gap + (2.0 * f32::from(style.tier_offsets.tier_3)),
         inverse,
+        style.anti_alias,
+    );
+}
+
+#[inline]
+fn draw_horizontal_bar_tier(
+    primitives: &mut Vec<Primitive>,
+    bounds: &Rectangle,
+    tick_marks: Option<&Vec<Normal>>,
+    shape: &Shape,
+    inverse: bool,
+    anti_alias: bool,
+) {
+    if let Some(tick_marks) = tick_marks {
+        if let Shape::Bar { thickness, color } = shape {
+            draw_horizontal_lines(
+                primitives,
+                tick_marks,
+                bounds.x,
+                bounds.width,
+                bounds.y,
+                *thickness,
+                bounds.height as u16,
+                *color,
+                inverse,
+                anti_alias,
+            );
+        }
+    }
+}
+
+fn draw_horizontal_bars(
+    primitives: &mut Vec<Primitive>,
+    bounds: &Rectangle,
+    tick_marks: &tick_marks::Group,
+    style: &Style,
+    inverse: bool,
+) {
+    draw_horizontal_bar_tier(
+        primitives,
+        bounds,
+        tick_marks.tier_1(),
+        &style.tier_1,
+        inverse,
+        style.anti_alias,
+    );
+    draw_horizontal_bar_tier(
+        primitives,
+        bounds,
+        tick_marks.tier_2(),
+        &style.tier_2,
+        inverse,
+        style.anti_alias,
+    );
+    draw_horizontal_bar_tier(
+        primitives,
+        bounds,
+        tick_marks.tier_3(),
+        &style.tier_3,
+        inverse,
+        style.anti_alias,
     );
 }
 
@@ -554,6 +856,14 @@ pub fn draw_horizontal_tick_marks(
                 );
             }
 
+            draw_horizontal_bars(
+                &mut primitives,
+                &bounds,
+                tick_marks,
+                style,
+                inverse,
+            );
+
             primitives
         }
         Placement::LeftOrTop { offset, inside } => {
@@ -582,6 +892,14 @@ pub fn draw_horizontal_tick_marks(
                 );
             }
 
+            draw_horizontal_bars(
+                &mut primitives,
+                &bounds,
+                tick_marks,
+                style,
+                inverse,
+            );
+
             primitives
         }
         Placement::RightOrBottom { offset, inside } => {
@@ -610,6 +928,14 @@ pub fn draw_horizontal_tick_marks(
                 );
             }
 
+            draw_horizontal_bars(
+                &mut primitives,
+                &bounds,
+                tick_marks,
+                style,
+                inverse,
+            );
+
             primitives
         }
         Placement::Center {
@@ -624,13 +950,21 @@ pub fn draw_horizontal_tick_marks(
             draw_horizontal_center_aligned(
                 &mut primitives,
                 &bounds,
-                bounds.center_y().round(),
+                super::snap(bounds.center_y(), style.anti_alias),
                 tick_marks,
                 style,
                 *fill_length,
                 inverse,
             );
 
+            draw_horizontal_bars(
+                &mut primitives,
+                &bounds,
+                tick_marks,
+                style,
+                inverse,
+            );
+
             primitives
         }
         Placement::CenterSplit {
@@ -646,7 +980,7 @@ pub fn draw_horizontal_tick_marks(
             draw_horizontal_center_aligned_split(
                 &mut primitives,
                 &bounds,
-                bounds.center_y().round(),
+                super::snap(bounds.center_y(), style.anti_alias),
                 tick_marks,
                 style,
                 *fill_length,
@@ -654,9 +988,21 @@ pub fn draw_horizontal_tick_marks(
                 inverse,
             );
 
+            draw_horizontal_bars(
+                &mut primitives,
+                &bounds,
+                tick_marks,
+                style,
+                inverse,
+            );
+
             primitives
         }
     };
 
-    Primitive::Group { primitives }
+    if style.anti_alias {
+        super::draw_anti_aliased(primitives)
+    } else {
+        Primitive::Group { primitives }
+    }
 }
@@ -10,3 +10,79 @@ mod vertical;
 pub use horizontal::*;
 pub use radial::*;
 pub use vertical::*;
+
+use iced_graphics::canvas::{Fill, Frame, Path};
+use iced_graphics::{Background, Primitive};
+use iced_native::{Point, Size, Vector};
+
+/// Rounds a position to a whole pixel, unless the tick marks are drawn
+/// anti-aliased, in which case the sub-pixel position is kept.
+#[inline]
+fn snap(position: f32, anti_alias: bool) -> f32 {
+    if anti_alias {
+        position
+    } else {
+        position.round()
+    }
+}
+
+/// Redraws a list of tick mark quads as anti-aliased canvas paths.
+fn draw_anti_aliased(primitives: Vec<Primitive>) -> Primitive {
+    let mut min_x = std::f32::MAX;
+    let mut min_y = std::f32::MAX;
+    let mut max_x = std::f32::MIN;
+    let mut max_y = std::f32::MIN;
+
+    for primitive in primitives.iter() {
+        if let Primitive::Quad { bounds, .. } = primitive {
+            min_x = min_x.min(bounds.x);
+            min_y = min_y.min(bounds.y);
+            max_x = max_x.max(bounds.x + bounds.width);
+            max_y = max_y.max(bounds.y + bounds.height);
+        }
+    }
+
+    if min_x >= max_x || min_y >= max_y {
+        return Primitive::None;
+    }
+
+    let mut frame = Frame::new(Size::new(max_x - min_x, max_y - min_y));
+
+    for primitive in primitives.iter() {
+        if let Primitive::Quad {
+            bounds,
+            background: Background::Color(color),
+            border_radius,
+            ..
+        } = primitive
+        {
+            let path = if *border_radius > 0 {
+                Path::circle(
+                    Point::new(
+                        bounds.center_x() - min_x,
+                        bounds.center_y() - min_y,
+                    ),
+                    bounds.width.min(bounds.height) / 2.0,
+                )
+            } else {
+                Path::rectangle(
+                    Point::new(bounds.x - min_x, bounds.y - min_y),
+                    Size::new(bounds.width, bounds.height),
+                )
+            };
+
+            frame.fill(
+                &path,
+                Fill {
+                    color: *color,
+                    ..Fill::default()
+                },
+            );
+        }
+    }
+
+    Primitive::Translate {
+        translation: Vector::new(min_x, min_y),
+        content: Box::new(frame.into_geometry().into_primitive()),
+    }
+}
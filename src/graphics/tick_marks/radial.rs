@@ -100,6 +100,58 @@ fn draw_radial_lines(
     }
 }
 
+fn draw_radial_dashed_lines(
+    frame: &mut Frame,
+    offset_radius: f32,
+    start_angle: f32,
+    angle_span: f32,
+    tick_marks: &[Normal],
+    color: Color,
+    width: f32,
+    length: f32,
+    dash: f32,
+    gap: f32,
+    inverse: bool,
+) {
+    let stroke = Stroke {
+        width,
+        color,
+        line_cap: LineCap::Butt,
+        ..Stroke::default()
+    };
+
+    let dash = dash.max(1.0);
+
+    let path = Path::new(|path| {
+        let mut dash_start = 0.0;
+        while dash_start < length {
+            path.move_to(Point::new(0.0, -offset_radius - dash_start));
+            path.line_to(Point::new(
+                0.0,
+                -offset_radius - (dash_start + dash).min(length),
+            ));
+
+            dash_start += dash + gap;
+        }
+    });
+
+    for tick_mark in tick_marks {
+        let angle = if inverse {
+            start_angle + tick_mark.scale_inv(angle_span)
+        } else {
+            start_angle + tick_mark.scale(angle_span)
+        };
+
+        frame.with_save(|frame| {
+            if angle < -0.001 || angle > 0.001 {
+                frame.rotate(angle);
+            }
+
+            frame.stroke(&path, stroke);
+        });
+    }
+}
+
 #[inline]
 fn draw_tier(
     frame: &mut Frame,
@@ -113,7 +165,7 @@ fn draw_tier(
 ) {
     if let Some(tick_marks) = tick_marks {
         match shape {
-            Shape::None => return,
+            Shape::None | Shape::Bar { .. } => return,
             Shape::Line {
                 length,
                 width,
@@ -148,6 +200,48 @@ fn draw_tier(
                     );
                 }
             }
+            Shape::DashedLine {
+                length,
+                width,
+                dash,
+                gap,
+                color,
+            } => {
+                let length = f32::from(*length);
+                let width = f32::from(*width);
+                let dash = f32::from(*dash);
+                let gap = f32::from(*gap);
+
+                if inside {
+                    draw_radial_dashed_lines(
+                        frame,
+                        offset_radius - length,
+                        start_angle,
+                        angle_span,
+                        tick_marks,
+                        *color,
+                        width,
+                        length,
+                        dash,
+                        gap,
+                        inverse,
+                    );
+                } else {
+                    draw_radial_dashed_lines(
+                        frame,
+                        offset_radius,
+                        start_angle,
+                        angle_span,
+                        tick_marks,
+                        *color,
+                        width,
+                        length,
+                        dash,
+                        gap,
+                        inverse,
+                    );
+                }
+            }
             Shape::Circle { diameter, color } => {
                 let radius = f32::from(*diameter) / 2.0;
 
@@ -181,26 +275,50 @@ fn draw_tier(
 
 fn max_length(style: &Style) -> f32 {
     let length_1 = match style.tier_1 {
-        Shape::None => 0,
+        Shape::None | Shape::Bar { .. } => 0,
         Shape::Line { length, .. } => length,
+        Shape::DashedLine { length, .. } => length,
         Shape::Circle { diameter, .. } => diameter,
     };
 
     let length_2 = match style.tier_1 {
-        Shape::None => 0,
+        Shape::None | Shape::Bar { .. } => 0,
         Shape::Line { length, .. } => length,
+        Shape::DashedLine { length, .. } => length,
         Shape::Circle { diameter, .. } => diameter,
     };
 
     let length_3 = match style.tier_1 {
-        Shape::None => 0,
+        Shape::None | Shape::Bar { .. } => 0,
         Shape::Line { length, .. } => length,
+        Shape::DashedLine { length, .. } => length,
         Shape::Circle { diameter, .. } => diameter,
     };
 
     f32::from(length_1.max(length_2).max(length_3))
 }
 
+fn max_offset(style: &Style) -> f32 {
+    let offsets = &style.tier_offsets;
+
+    f32::from(
+        offsets
+            .tier_1
+            .max(offsets.tier_2)
+            .max(offsets.tier_3)
+            .max(0),
+    )
+}
+
+#[inline]
+fn tier_radius(radius: f32, offset: i16, inside: bool) -> f32 {
+    if inside {
+        radius - f32::from(offset)
+    } else {
+        radius + f32::from(offset)
+    }
+}
+
 /// Draws tick marks around an arc.
 ///
 /// * `center` - The center point of the arc.
@@ -226,7 +344,7 @@ pub fn draw_radial_tick_marks(
     let frame_radius = if inside {
         radius
     } else {
-        radius + max_length(style)
+        radius + max_length(style) + max_offset(style)
     };
 
     let frame_size = frame_radius * 2.0;
@@ -237,7 +355,7 @@ pub fn draw_radial_tick_marks(
 
     draw_tier(
         &mut frame,
-        radius,
+        tier_radius(radius, style.tier_offsets.tier_1, inside),
         start_angle,
         angle_span,
         tick_marks.tier_1(),
@@ -247,7 +365,7 @@ pub fn draw_radial_tick_marks(
     );
     draw_tier(
         &mut frame,
-        radius,
+        tier_radius(radius, style.tier_offsets.tier_2, inside),
         start_angle,
         angle_span,
         tick_marks.tier_2(),
@@ -257,7 +375,7 @@ pub fn draw_radial_tick_marks(
     );
     draw_tier(
         &mut frame,
-        radius,
+        tier_radius(radius, style.tier_offsets.tier_3, inside),
         start_angle,
         angle_span,
         tick_marks.tier_3(),
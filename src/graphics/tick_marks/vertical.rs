@@ -15,6 +15,7 @@ fn draw_vertical_lines(
     length: u16,
     color: Color,
     inverse: bool,
+    anti_alias: bool,
 ) {
     let start_y = bounds_y - (f32::from(width) / 2.0);
     let back_color = Background::Color(color);
@@ -24,7 +25,10 @@ fn draw_vertical_lines(
             primitives.push(Primitive::Quad {
                 bounds: Rectangle {
                     x,
-                    y: (start_y + tick_mark.scale(bounds_height)).round(),
+                    y: super::snap(
+                        start_y + tick_mark.scale(bounds_height),
+                        anti_alias,
+                    ),
                     width: f32::from(length),
                     height: f32::from(width),
                 },
@@ -39,7 +43,10 @@ fn draw_vertical_lines(
             primitives.push(Primitive::Quad {
                 bounds: Rectangle {
                     x,
-                    y: (start_y + tick_mark.scale_inv(bounds_height)).round(),
+                    y: super::snap(
+                        start_y + tick_mark.scale_inv(bounds_height),
+                        anti_alias,
+                    ),
                     width: f32::from(length),
                     height: f32::from(width),
                 },
@@ -52,6 +59,56 @@ fn draw_vertical_lines(
     }
 }
 
+fn draw_vertical_dashed_lines(
+    primitives: &mut Vec<Primitive>,
+    tick_marks: &[Normal],
+    bounds_y: f32,
+    bounds_height: f32,
+    x: f32,
+    width: u16,
+    length: u16,
+    dash: u16,
+    gap: u16,
+    color: Color,
+    inverse: bool,
+    anti_alias: bool,
+) {
+    let start_y = bounds_y - (f32::from(width) / 2.0);
+    let back_color = Background::Color(color);
+    let length = f32::from(length);
+    let dash = f32::from(dash.max(1));
+    let step = dash + f32::from(gap);
+
+    for tick_mark in tick_marks {
+        let y = if inverse {
+            super::snap(start_y + tick_mark.scale(bounds_height), anti_alias)
+        } else {
+            super::snap(
+                start_y + tick_mark.scale_inv(bounds_height),
+                anti_alias,
+            )
+        };
+
+        let mut dash_start = 0.0;
+        while dash_start < length {
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: x + dash_start,
+                    y,
+                    width: dash.min(length - dash_start),
+                    height: f32::from(width),
+                },
+                background: back_color,
+                border_radius: 0,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            });
+
+            dash_start += step;
+        }
+    }
+}
+
 fn draw_vertical_circles(
     primitives: &mut Vec<Primitive>,
     tick_marks: &[Normal],
@@ -61,6 +118,7 @@ fn draw_vertical_circles(
     diameter: u16,
     color: Color,
     inverse: bool,
+    anti_alias: bool,
 ) {
     let diameter = f32::from(diameter);
     let radius = (diameter / 2.0).round() as u16;
@@ -72,7 +130,10 @@ fn draw_vertical_circles(
             primitives.push(Primitive::Quad {
                 bounds: Rectangle {
                     x,
-                    y: (start_y + tick_mark.scale(bounds_height)).round(),
+                    y: super::snap(
+                        start_y + tick_mark.scale(bounds_height),
+                        anti_alias,
+                    ),
                     width: diameter,
                     height: diameter,
                 },
@@ -87,7 +148,10 @@ fn draw_vertical_circles(
             primitives.push(Primitive::Quad {
                 bounds: Rectangle {
                     x,
-                    y: (start_y + tick_mark.scale_inv(bounds_height)).round(),
+                    y: super::snap(
+                        start_y + tick_mark.scale_inv(bounds_height),
+                        anti_alias,
+                    ),
                     width: diameter,
                     height: diameter,
                 },
@@ -108,10 +172,11 @@ fn draw_vertical_left_aligned_tier(
     tick_marks: Option<&Vec<Normal>>,
     shape: &Shape,
     inverse: bool,
+    anti_alias: bool,
 ) {
     if let Some(tick_marks) = tick_marks {
         match shape {
-            Shape::None => return,
+            Shape::None | Shape::Bar { .. } => return,
             Shape::Line {
                 length,
                 width,
@@ -127,6 +192,29 @@ fn draw_vertical_left_aligned_tier(
                     *length,
                     *color,
                     inverse,
+                    anti_alias,
+                );
+            }
+            Shape::DashedLine {
+                length,
+                width,
+                dash,
+                gap,
+                color,
+            } => {
+                draw_vertical_dashed_lines(
+                    primitives,
+                    tick_marks,
+                    bounds.y,
+                    bounds.height,
+                    x,
+                    *width,
+                    *length,
+                    *dash,
+                    *gap,
+                    *color,
+                    inverse,
+                    anti_alias,
                 );
             }
             Shape::Circle { diameter, color } => {
@@ -139,6 +227,7 @@ fn draw_vertical_left_aligned_tier(
                     *diameter,
                     *color,
                     inverse,
+                    anti_alias,
                 );
             }
         }
@@ -156,26 +245,29 @@ fn draw_vertical_left_aligned(
     draw_vertical_left_aligned_tier(
         primitives,
         bounds,
-        x,
+        x + f32::from(style.tier_offsets.tier_1),
         tick_marks.tier_1(),
         &style.tier_1,
         inverse,
+        style.anti_alias,
     );
     draw_vertical_left_aligned_tier(
         primitives,
         bounds,
-        x,
+        x + f32::from(style.tier_offsets.tier_2),
         tick_marks.tier_2(),
         &style.tier_2,
         inverse,
+        style.anti_alias,
     );
     draw_vertical_left_aligned_tier(
         primitives,
         bounds,
-        x,
+        x + f32::from(style.tier_offsets.tier_3),
         tick_marks.tier_3(),
         &style.tier_3,
         inverse,
+        style.anti_alias,
     );
 }
 
@@ -187,10 +279,11 @@ fn draw_vertical_right_aligned_tier(
     tick_marks: Option<&Vec<Normal>>,
     shape: &Shape,
     inverse: bool,
+    anti_alias: bool,
 ) {
     if let Some(tick_marks) = tick_marks {
         match shape {
-            Shape::None => return,
+            Shape::None | Shape::Bar { .. } => return,
             Shape::Line {
                 length,
                 width,
@@ -206,6 +299,29 @@ fn draw_vertical_right_aligned_tier(
                     *length,
                     *color,
                     inverse,
+                    anti_alias,
+                );
+            }
+            Shape::DashedLine {
+                length,
+                width,
+                dash,
+                gap,
+                color,
+            } => {
+                draw_vertical_dashed_lines(
+                    primitives,
+                    tick_marks,
+                    bounds.y,
+                    bounds.height,
+                    x - f32::from(*length),
+                    *width,
+                    *length,
+                    *dash,
+                    *gap,
+                    *color,
+                    inverse,
+                    anti_alias,
                 );
             }
             Shape::Circle { diameter, color } => {
@@ -218,6 +334,7 @@ fn draw_vertical_right_aligned_tier(
                     *diameter,
                     *color,
                     inverse,
+                    anti_alias,
                 );
             }
         }
@@ -235,26 +352,29 @@ fn draw_vertical_right_aligned(
     draw_vertical_right_aligned_tier(
         primitives,
         bounds,
-        x,
+        x - f32::from(style.tier_offsets.tier_1),
         tick_marks.tier_1(),
         &style.tier_1,
         inverse,
+        style.anti_alias,
     );
     draw_vertical_right_aligned_tier(
         primitives,
         bounds,
-        x,
+        x - f32::from(style.tier_offsets.tier_2),
         tick_marks.tier_2(),
         &style.tier_2,
         inverse,
+        style.anti_alias,
     );
     draw_vertical_right_aligned_tier(
         primitives,
         bounds,
-        x,
+        x - f32::from(style.tier_offsets.tier_3),
         tick_marks.tier_3(),
         &style.tier_3,
         inverse,
+        style.anti_alias,
     );
 }
 
@@ -267,10 +387,11 @@ fn draw_vertical_center_aligned_tier(
     shape: &Shape,
     fill_length: bool,
     inverse: bool,
+    anti_alias: bool,
 ) {
     if let Some(tick_marks) = tick_marks {
         match shape {
-            Shape::None => return,
+            Shape::None | Shape::Bar { .. } => return,
             Shape::Line {
                 length,
                 width,
@@ -282,7 +403,10 @@ fn draw_vertical_center_aligned_tier(
                         (bounds.width - (f32::from(*length) * 2.0)) as u16,
                     )
                 } else {
-                    ((x - (f32::from(*length) / 2.0)).round(), *length)
+                    (
+                        super::snap(x - (f32::from(*length) / 2.0), anti_alias),
+                        *length,
+                    )
                 };
 
                 draw_vertical_lines(
@@ -295,6 +419,41 @@ fn draw_vertical_center_aligned_tier(
                     length,
                     *color,
                     inverse,
+                    anti_alias,
+                );
+            }
+            Shape::DashedLine {
+                length,
+                width,
+                dash,
+                gap,
+                color,
+            } => {
+                let (x, length) = if fill_length {
+                    (
+                        bounds.x + f32::from(*length),
+                        (bounds.width - (f32::from(*length) * 2.0)) as u16,
+                    )
+                } else {
+                    (
+                        super::snap(x - (f32::from(*length) / 2.0), anti_alias),
+                        *length,
+                    )
+                };
+
+                draw_vertical_dashed_lines(
+                    primitives,
+                    tick_marks,
+                    bounds.y,
+                    bounds.height,
+                    x,
+                    *width,
+                    length,
+                    *dash,
+                    *gap,
+                    *color,
+                    inverse,
+                    anti_alias,
                 );
             }
             Shape::Circle { diameter, color } => {
@@ -304,7 +463,13 @@ fn draw_vertical_center_aligned_tier(
                         (bounds.width - (f32::from(*diameter) * 2.0)) as u16,
                     )
                 } else {
-                    ((x - (f32::from(*diameter) / 2.0)).round(), *diameter)
+                    (
+                        super::snap(
+                            x - (f32::from(*diameter) / 2.0),
+                            anti_alias,
+                        ),
+                        *diameter,
+                    )
                 };
 
                 draw_vertical_circles(
@@ -316,6 +481,7 @@ fn draw_vertical_center_aligned_tier(
                     diameter,
                     *color,
                     inverse,
+                    anti_alias,
                 );
             }
         }
@@ -339,6 +505,7 @@ fn draw_vertical_center_aligned(
         &style.tier_1,
         fill_length,
         inverse,
+        style.anti_alias,
     );
     draw_vertical_center_aligned_tier(
         primitives,
@@ -348,6 +515,7 @@ fn draw_vertical_center_aligned(
         &style.tier_2,
         fill_length,
         inverse,
+        style.anti_alias,
     );
     draw_vertical_center_aligned_tier(
         primitives,
@@ -357,6 +525,7 @@ fn draw_vertical_center_aligned(
         &style.tier_3,
         fill_length,
         inverse,
+        style.anti_alias,
     );
 }
 
@@ -370,10 +539,11 @@ fn draw_vertical_center_aligned_split_tier(
     fill_length: bool,
     gap: f32,
     inverse: bool,
+    anti_alias: bool,
 ) {
     if let Some(tick_marks) = tick_marks {
         match shape {
-            Shape::None => return,
+            Shape::None | Shape::Bar { .. } => return,
             Shape::Line {
                 length,
                 width,
@@ -383,12 +553,21 @@ fn draw_vertical_center_aligned_split_tier(
                     let length = (f32::from(*length)
                         + ((bounds.width + gap) / 2.0))
                         .round();
-                    ((x - length - (gap / 2.0)).round(), length as u16)
+                    (
+                        super::snap(x - length - (gap / 2.0), anti_alias),
+                        length as u16,
+                    )
                 } else {
-                    ((x - f32::from(*length) - (gap / 2.0)).round(), *length)
+                    (
+                        super::snap(
+                            x - f32::from(*length) - (gap / 2.0),
+                            anti_alias,
+                        ),
+                        *length,
+                    )
                 };
 
-                let right_x = (x + (gap / 2.0)).round();
+                let right_x = super::snap(x + (gap / 2.0), anti_alias);
 
                 draw_vertical_lines(
                     primitives,
@@ -400,6 +579,7 @@ fn draw_vertical_center_aligned_split_tier(
                     length,
                     *color,
                     inverse,
+                    anti_alias,
                 );
                 draw_vertical_lines(
                     primitives,
@@ -411,6 +591,63 @@ fn draw_vertical_center_aligned_split_tier(
                     length,
                     *color,
                     inverse,
+                    anti_alias,
+                );
+            }
+            Shape::DashedLine {
+                length,
+                width,
+                dash,
+                gap: dash_gap,
+                color,
+            } => {
+                let (left_x, length) = if fill_length {
+                    let length = (f32::from(*length)
+                        + ((bounds.width + gap) / 2.0))
+                        .round();
+                    (
+                        super::snap(x - length - (gap / 2.0), anti_alias),
+                        length as u16,
+                    )
+                } else {
+                    (
+                        super::snap(
+                            x - f32::from(*length) - (gap / 2.0),
+                            anti_alias,
+                        ),
+                        *length,
+                    )
+                };
+
+                let right_x = super::snap(x + (gap / 2.0), anti_alias);
+
+                draw_vertical_dashed_lines(
+                    primitives,
+                    tick_marks,
+                    bounds.y,
+                    bounds.height,
+                    left_x,
+                    *width,
+                    length,
+                    *dash,
+                    *dash_gap,
+                    *color,
+                    inverse,
+                    anti_alias,
+                );
+                draw_vertical_dashed_lines(
+                    primitives,
+                    tick_marks,
+                    bounds.y,
+                    bounds.height,
+                    right_x,
+                    *width,
+                    length,
+                    *dash,
+                    *dash_gap,
+                    *color,
+                    inverse,
+                    anti_alias,
                 );
             }
             Shape::Circle { diameter, color } => {
@@ -422,12 +659,15 @@ fn draw_vertical_center_aligned_split_tier(
                     )
                 } else {
                     (
-                        (x - f32::from(*diameter) - (gap / 2.0)).round(),
+                        super::snap(
+                            x - f32::from(*diameter) - (gap / 2.0),
+                            anti_alias,
+                        ),
                         *diameter,
                     )
                 };
 
-                let right_x = (x + (gap / 2.0)).round();
+                let right_x = super::snap(x + (gap / 2.0), anti_alias);
 
                 draw_vertical_circles(
                     primitives,
@@ -438,6 +678,7 @@ fn draw_vertical_center_aligned_split_tier(
                     diameter,
                     *color,
                     inverse,
+                    anti_alias,
                 );
                 draw_vertical_circles(
                     primitives,
@@ -448,6 +689,7 @@ fn draw_vertical_center_aligned_split_tier(
                     diameter,
                     *color,
                     inverse,
+                    anti_alias,
                 );
             }
         }
@@ -471,8 +713,9 @@ fn draw_vertical_center_aligned_split(
         tick_marks.tier_1(),
         &style.tier_1,
         fill_length,
-        gap,
+        gap + (2.0 * f32::from(style.tier_offsets.tier_1)),
         inverse,
+        style.anti_alias,
     );
     draw_vertical_center_aligned_split_tier(
         primitives,
@@ -481,8 +724,9 @@ fn draw_vertical_center_aligned_split(
         tick_marks.tier_2(),
         &style.tier_2,
         fill_length,
-        gap,
+        gap + (2.0 * f32::from(style.tier_offsets.tier_2)),
         inverse,
+        style.anti_alias,
     );
     draw_vertical_center_aligned_split_tier(
         primitives,
@@ -491,8 +735,69 @@ fn draw_vertical_center_aligned_split(
         tick_marks.tier_3(),
         &style.tier_3,
         fill_length,
-        gap,
+        gap + (2.0 * f32::from(style.tier_offsets.tier_3)),
         inverse,
+        style.anti_alias,
+    );
+}
+
+#[inline]
+fn draw_vertical_bar_tier(
+    primitives: &mut Vec<Primitive>,
+    bounds: &Rectangle,
+    tick_marks: Option<&Vec<Normal>>,
+    shape: &Shape,
+    inverse: bool,
+    anti_alias: bool,
+) {
+    if let Some(tick_marks) = tick_marks {
+        if let Shape::Bar { thickness, color } = shape {
+            draw_vertical_lines(
+                primitives,
+                tick_marks,
+                bounds.y,
+                bounds.height,
+                bounds.x,
+                *thickness,
+                bounds.width as u16,
+                *color,
+                inverse,
+                anti_alias,
+            );
+        }
+    }
+}
+
+fn draw_vertical_bars(
+    primitives: &mut Vec<Primitive>,
+    bounds: &Rectangle,
+    tick_marks: &tick_marks::Group,
+    style: &Style,
+    inverse: bool,
+) {
+    draw_vertical_bar_tier(
+        primitives,
+        bounds,
+        tick_marks.tier_1(),
+        &style.tier_1,
+        inverse,
+        style.anti_alias,
+    );
+    draw_vertical_bar_tier(
+        primitives,
+        bounds,
+        tick_marks.tier_2(),
+        &style.tier_2,
+        inverse,
+        style.anti_alias,
+    );
+    draw_vertical_bar_tier(
+        primitives,
+        bounds,
+        tick_marks.tier_3(),
+        &style.tier_3,
+        inverse,
+        style.anti_alias,
     );
 }
 
@@ -554,6 +859,14 @@ pub fn draw_vertical_tick_marks(
                 );
             }
 
+            draw_vertical_bars(
+                &mut primitives,
+                &bounds,
+                tick_marks,
+                style,
+                inverse,
+            );
+
             primitives
         }
         Placement::LeftOrTop { offset, inside } => {
@@ -582,6 +895,14 @@ pub fn draw_vertical_tick_marks(
                 );
             }
 
+            draw_vertical_bars(
+                &mut primitives,
+                &bounds,
+                tick_marks,
+                style,
+                inverse,
+            );
+
             primitives
         }
         Placement::RightOrBottom { offset, inside } => {
@@ -610,6 +931,14 @@ pub fn draw_vertical_tick_marks(
                 );
             }
 
+            draw_vertical_bars(
+                &mut primitives,
+                &bounds,
+                tick_marks,
+                style,
+                inverse,
+            );
+
             primitives
         }
         Placement::Center {
@@ -624,13 +953,21 @@ pub fn draw_vertical_tick_marks(
             draw_vertical_center_aligned(
                 &mut primitives,
                 &bounds,
-                bounds.center_x().round(),
+                super::snap(bounds.center_x(), style.anti_alias),
                 tick_marks,
                 style,
                 *fill_length,
                 inverse,
             );
 
+            draw_vertical_bars(
+                &mut primitives,
+                &bounds,
+                tick_marks,
+                style,
+                inverse,
+            );
+
             primitives
         }
         Placement::CenterSplit {
@@ -646,7 +983,7 @@ pub fn draw_vertical_tick_marks(
             draw_vertical_center_aligned_split(
                 &mut primitives,
                 &bounds,
-                bounds.center_x().round(),
+                super::snap(bounds.center_x(), style.anti_alias),
                 tick_marks,
                 style,
                 *fill_length,
@@ -654,9 +991,21 @@ pub fn draw_vertical_tick_marks(
                 inverse,
             );
 
+            draw_vertical_bars(
+                &mut primitives,
+                &bounds,
+                tick_marks,
+                style,
+                inverse,
+            );
+
             primitives
         }
     };
 
-    Primitive::Group { primitives }
+    if style.anti_alias {
+        super::draw_anti_aliased(primitives)
+    } else {
+        Primitive::Group { primitives }
+    }
 }
@@ -0,0 +1,62 @@
+//! `iced_graphics` renderer for a value tooltip
+
+pub use crate::style::tooltip::*;
+
+use iced_graphics::{
+    Background, HorizontalAlignment, Point, Primitive, Rectangle,
+    VerticalAlignment,
+};
+
+/// Draws a tooltip near the cursor.
+///
+/// * cursor_position - The position of the cursor.
+/// * text - The text to display.
+/// * style - The tooltip style.
+pub fn draw_tooltip(
+    cursor_position: Point,
+    text: &str,
+    style: &Style,
+) -> Primitive {
+    let char_count = text.chars().count() as f32;
+
+    // Grow the tooltip to fit the estimated width of the text.
+    let width = (char_count * f32::from(style.text_size) * style.char_width
+        + (2.0 * f32::from(style.padding)))
+    .max(f32::from(style.min_width))
+    .round();
+
+    let bounds = Rectangle {
+        x: (cursor_position.x + style.offset.x_f32()).round(),
+        y: (cursor_position.y + style.offset.y_f32() - f32::from(style.height))
+            .round(),
+        width,
+        height: f32::from(style.height),
+    };
+
+    let back = Primitive::Quad {
+        bounds,
+        background: Background::Color(style.back_color),
+        border_radius: style.border_radius,
+        border_width: style.border_width,
+        border_color: style.border_color,
+    };
+
+    let text = Primitive::Text {
+        content: String::from(text),
+        size: f32::from(style.text_size),
+        bounds: Rectangle {
+            x: bounds.center_x(),
+            y: bounds.center_y(),
+            width: bounds.width,
+            height: bounds.height,
+        },
+        color: style.text_color,
+        font: style.font,
+        horizontal_alignment: HorizontalAlignment::Center,
+        vertical_alignment: VerticalAlignment::Center,
+    };
+
+    Primitive::Group {
+        primitives: vec![back, text],
+    }
+}
@@ -0,0 +1,88 @@
+//! Display tick marks and text marks on their own, such as shared marks
+//! placed between two stacked sliders
+
+use crate::graphics::{text_marks, tick_marks};
+use crate::native::value_marks;
+use iced_graphics::{Backend, Primitive, Renderer};
+use iced_native::{mouse, Rectangle};
+
+pub use crate::native::value_marks::Orientation;
+pub use crate::style::value_marks::{
+    StyleSheet, TextMarksStyle, TickMarksStyle,
+};
+
+/// A widget that draws a group of tick marks and/or text marks into its
+/// bounds, decoupled from any slider.
+pub type ValueMarks<'a, Backend> =
+    value_marks::ValueMarks<'a, Renderer<Backend>>;
+
+impl<B: Backend> value_marks::Renderer for Renderer<B> {
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        orientation: Orientation,
+        tick_marks: Option<&tick_marks::Group>,
+        text_marks: Option<&text_marks::Group>,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let bounds = Rectangle {
+            x: bounds.x.round(),
+            y: bounds.y.round(),
+            width: bounds.width.round(),
+            height: bounds.height.round(),
+        };
+
+        let tick_marks = match (tick_marks, style_sheet.tick_marks_style()) {
+            (Some(tick_marks), Some(style)) => match orientation {
+                Orientation::Horizontal => {
+                    tick_marks::draw_horizontal_tick_marks(
+                        &bounds,
+                        tick_marks,
+                        &style.style,
+                        &style.placement,
+                        false,
+                    )
+                }
+                Orientation::Vertical => tick_marks::draw_vertical_tick_marks(
+                    &bounds,
+                    tick_marks,
+                    &style.style,
+                    &style.placement,
+                    false,
+                ),
+            },
+            _ => Primitive::None,
+        };
+
+        let text_marks = match (text_marks, style_sheet.text_marks_style()) {
+            (Some(text_marks), Some(style)) => match orientation {
+                Orientation::Horizontal => {
+                    text_marks::draw_horizontal_text_marks(
+                        &bounds,
+                        text_marks,
+                        &style.style,
+                        &style.placement,
+                        false,
+                    )
+                }
+                Orientation::Vertical => text_marks::draw_vertical_text_marks(
+                    &bounds,
+                    text_marks,
+                    &style.style,
+                    &style.placement,
+                    false,
+                ),
+            },
+            _ => Primitive::None,
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![tick_marks, text_marks],
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
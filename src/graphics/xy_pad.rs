@@ -9,6 +9,7 @@ use iced_graphics::{Backend, Primitive, Renderer};
 use iced_native::{mouse, Background, Color, Point, Rectangle};
 
 pub use crate::native::xy_pad::State;
+pub use crate::native::AccelCurve;
 pub use crate::style::xy_pad::{
     HandleCircle, HandleShape, HandleSquare, Style, StyleSheet,
 };
@@ -211,14 +211,15 @@ pub use crate::core::*;
 mod platform {
     #[doc(no_inline)]
     pub use crate::graphics::{
-        h_slider, knob, mod_range_input, ramp, text_marks, tick_marks,
-        v_slider, xy_pad,
+        h_slider, knob, mod_range_input, ramp, segment_display, text_marks,
+        tick_marks, v_slider, value_marks, xy_pad,
     };
 
     #[doc(no_inline)]
     pub use {
         h_slider::HSlider, knob::Knob, mod_range_input::ModRangeInput,
-        ramp::Ramp, v_slider::VSlider, xy_pad::XYPad,
+        ramp::Ramp, segment_display::SegmentDisplay, v_slider::VSlider,
+        value_marks::ValueMarks, xy_pad::XYPad,
     };
 }
 
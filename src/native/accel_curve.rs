@@ -0,0 +1,63 @@
+//! How the sensitivity of fine adjustments grows during a drag gesture
+
+/// How the sensitivity of fine adjustments (dragging while holding the
+/// modifier keys) grows during a drag gesture
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AccelCurve {
+    /// The sensitivity grows with the distance the cursor has moved in fine
+    /// mode during the current drag gesture.
+    Distance {
+        /// The distance in pixels at which the sensitivity has doubled
+        pixels: f32,
+        /// The maximum factor the modifier scalar is multiplied by
+        max: f32,
+    },
+    /// The sensitivity grows with the speed of the cursor.
+    Speed {
+        /// The distance in pixels moved in a single cursor event at which
+        /// the sensitivity has doubled
+        pixels: f32,
+        /// The maximum factor the modifier scalar is multiplied by
+        max: f32,
+    },
+}
+
+impl AccelCurve {
+    /// Returns the factor the modifier scalar is multiplied by.
+    ///
+    /// It expects:
+    ///   * the distance in pixels the cursor has moved in fine mode during
+    ///   the current drag gesture
+    ///   * the distance in pixels the cursor moved in the latest cursor event
+    ///
+    /// The factor is always between `1.0` and `max`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iced_audio::native::AccelCurve;
+    ///
+    /// let accel = AccelCurve::Distance {
+    ///     pixels: 100.0,
+    ///     max: 4.0,
+    /// };
+    ///
+    /// assert_eq!(accel.factor(0.0, 1.0), 1.0);
+    /// assert_eq!(accel.factor(100.0, 1.0), 2.0);
+    /// assert_eq!(accel.factor(1000.0, 1.0), 4.0);
+    /// ```
+    pub fn factor(&self, distance: f32, delta: f32) -> f32 {
+        let (amount, pixels, max) = match *self {
+            AccelCurve::Distance { pixels, max } => (distance, pixels, max),
+            AccelCurve::Speed { pixels, max } => (delta, pixels, max),
+        };
+
+        let max = max.max(1.0);
+
+        if pixels <= 0.0 {
+            return max;
+        }
+
+        (1.0 + (amount.abs() / pixels)).min(max)
+    }
+}
@@ -0,0 +1,21 @@
+//! Which mouse movements change the value of a widget while it is dragged
+
+/// Which mouse movements change the value of a widget while it is dragged
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DragAxis {
+    /// Only vertical movement changes the value. Moving up increases the
+    /// value.
+    Vertical,
+    /// Only horizontal movement changes the value. Moving right increases
+    /// the value.
+    Horizontal,
+    /// Both vertical and horizontal movement change the value. Moving up or
+    /// right increases the value.
+    Both,
+}
+
+impl Default for DragAxis {
+    fn default() -> Self {
+        DragAxis::Vertical
+    }
+}
@@ -0,0 +1,19 @@
+//! How a slider responds when it is first pressed
+
+/// How a slider responds when the mouse button is first pressed on it
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DragMode {
+    /// Pressing the slider only begins a drag gesture. The value is then
+    /// moved relative to the point where the slider was pressed.
+    Relative,
+    /// Pressing the slider jumps the value to the position of the cursor,
+    /// and the handle then follows the cursor as it is dragged. The drag
+    /// scalar is not applied, but the modifier scalar still is.
+    Absolute,
+}
+
+impl Default for DragMode {
+    fn default() -> Self {
+        DragMode::Relative
+    }
+}
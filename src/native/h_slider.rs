@@ -10,13 +10,17 @@ use iced_native::{
 };
 
 use std::hash::Hash;
+use std::time::{Duration, Instant};
 
-use crate::core::{ModulationRange, Normal, NormalParam};
-use crate::native::{text_marks, tick_marks};
+use crate::core::{ModulationRange, Normal, NormalFloat, NormalParam};
+use crate::native::{text_marks, tick_marks, AccelCurve, DragMode, OverTravel};
 
 static DEFAULT_HEIGHT: u16 = 14;
 static DEFAULT_SCALAR: f32 = 0.98;
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_KEYBOARD_STEP: f32 = 0.01;
+static COALESCE_INTERVAL: Duration = Duration::from_millis(16);
+static DEFAULT_GESTURE_END_DEBOUNCE: Duration = Duration::from_millis(200);
 
 /// A horizontal slider GUI widget that controls a [`NormalParam`]
 ///
@@ -28,12 +32,31 @@ static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
 pub struct HSlider<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     on_change: Box<dyn Fn(Normal) -> Message>,
+    on_grab: Option<Box<dyn Fn() -> Message>>,
+    on_release: Option<Box<dyn Fn() -> Message>>,
+    on_gesture_end: Option<Box<dyn Fn() -> Message>>,
+    gesture_end_debounce: Duration,
+    tooltip: Option<Box<dyn Fn(Normal) -> String>>,
+    accessibility_label: Option<String>,
+    value_description: Option<Box<dyn Fn(Normal) -> String>>,
     scalar: f32,
     modifier_scalar: f32,
+    fine_mode_accel: Option<AccelCurve>,
     modifier_keys: keyboard::ModifiersState,
+    keyboard_step: f32,
+    coalesce_messages: bool,
+    preview_keys: Option<keyboard::ModifiersState>,
+    reset_on_right_click: bool,
+    grab_padding: u16,
+    handle_width: Option<u16>,
+    end_padding: u16,
+    drag_mode: DragMode,
+    over_travel: OverTravel,
     width: Length,
     height: Length,
     style: Renderer::Style,
+    scale: f32,
+    layout_key: Option<u64>,
     tick_marks: Option<&'a tick_marks::Group>,
     text_marks: Option<&'a text_marks::Group>,
     mod_range_1: Option<&'a ModulationRange>,
@@ -56,15 +79,34 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         HSlider {
             state,
             on_change: Box::new(on_change),
+            on_grab: None,
+            on_release: None,
+            on_gesture_end: None,
+            gesture_end_debounce: DEFAULT_GESTURE_END_DEBOUNCE,
+            tooltip: None,
+            accessibility_label: None,
+            value_description: None,
             scalar: DEFAULT_SCALAR,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            fine_mode_accel: None,
             modifier_keys: keyboard::ModifiersState {
                 control: true,
                 ..Default::default()
             },
+            keyboard_step: DEFAULT_KEYBOARD_STEP,
+            coalesce_messages: false,
+            preview_keys: None,
+            reset_on_right_click: true,
+            grab_padding: 0,
+            handle_width: None,
+            end_padding: 0,
+            drag_mode: DragMode::default(),
+            over_travel: OverTravel::default(),
             width: Length::Fill,
             height: Length::from(Length::Units(DEFAULT_HEIGHT)),
             style: Renderer::Style::default(),
+            scale: 1.0,
+            layout_key: None,
             tick_marks: None,
             text_marks: None,
             mod_range_1: None,
@@ -72,6 +114,69 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         }
     }
 
+    /// Sets a function that will be called when the user grabs the
+    /// [`HSlider`] to begin a drag gesture.
+    ///
+    /// This message is sent independently of the value change messages,
+    /// so it can be used to begin a gesture such as writing host automation.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn on_grab<F>(mut self, on_grab: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets a function that will be called when the user releases
+    /// an [`HSlider`] that was being dragged.
+    ///
+    /// This message is sent independently of the value change messages,
+    /// so it can be used to end a gesture such as writing host automation.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn on_release<F>(mut self, on_release: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Sets a function that will be called when a drag gesture on the
+    /// [`HSlider`] has ended.
+    ///
+    /// Unlike `on_release`, this message is only sent once the [`HSlider`]
+    /// has been released and not grabbed again for the duration set with
+    /// [`gesture_end_debounce`]. A quick re-grab continues the same gesture,
+    /// which avoids spurious begin/end pairs when writing host automation.
+    ///
+    /// Widgets only run when they receive events, so the message is sent
+    /// with the first event (such as a cursor movement) that arrives after
+    /// the debounce has elapsed.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`gesture_end_debounce`]: struct.HSlider.html#method.gesture_end_debounce
+    pub fn on_gesture_end<F>(mut self, on_gesture_end: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_gesture_end = Some(Box::new(on_gesture_end));
+        self
+    }
+
+    /// Sets how long the [`HSlider`] must stay released before the
+    /// `on_gesture_end` message is sent.
+    ///
+    /// The default debounce is `200` milliseconds.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn gesture_end_debounce(mut self, debounce: Duration) -> Self {
+        self.gesture_end_debounce = debounce;
+        self
+    }
+
     /// Sets the width of the [`HSlider`].
     ///
     /// The default height is `Length::Fill`.
@@ -100,6 +205,31 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the factor all sizes in the style of the [`HSlider`] are multiplied
+    /// by when it is drawn, such as the rail, the handle, tick marks, and
+    /// text marks. Use this with the scale factor of the window to support
+    /// HiDPI displays without a separate set of styles.
+    ///
+    /// The default scale is `1.0`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = scale.max(0.0);
+        self
+    }
+
+    /// Sets a key that is included in the layout hash of the [`HSlider`].
+    ///
+    /// Changing the key forces a relayout, which is useful when the style or
+    /// textures are swapped at runtime in a way that changes how the
+    /// [`HSlider`] should be laid out.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn layout_key(mut self, key: u64) -> Self {
+        self.layout_key = Some(key);
+        self
+    }
+
     /// Sets the modifier keys of the [`HSlider`].
     ///
     /// The default modifier key is `Ctrl`.
@@ -140,6 +270,161 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets an [`AccelCurve`] that increases the modifier scalar during a
+    /// drag gesture, either with the distance dragged while holding the
+    /// modifier keys or with the speed of the cursor.
+    ///
+    /// The default is `None`, which uses the constant modifier scalar.
+    ///
+    /// [`AccelCurve`]: ../accel_curve/enum.AccelCurve.html
+    pub fn fine_mode_accel(mut self, accel: Option<AccelCurve>) -> Self {
+        self.fine_mode_accel = accel;
+        self
+    }
+
+    /// Sets the amount the value of the [`HSlider`] changes when the arrow
+    /// keys are pressed while it has keyboard focus.
+    ///
+    /// The default step is `0.01`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn keyboard_step(mut self, step: f32) -> Self {
+        self.keyboard_step = step;
+        self
+    }
+
+    /// Sets whether the [`HSlider`] coalesces the messages it sends while
+    /// being dragged.
+    ///
+    /// When enabled, a fast drag sends at most one `on_change` message per
+    /// frame (about 16 ms) instead of one per cursor movement, which keeps the
+    /// update loop from being flooded. The latest value is always sent when
+    /// the drag gesture is released, and it can be flushed sooner with
+    /// [`State::take_pending_change`].
+    ///
+    /// Note this lowers the resolution of the values the application
+    /// receives, so automation recorded from these messages will have fewer
+    /// points and may miss short movements between frames. Leave this
+    /// disabled when recording host automation at full resolution.
+    ///
+    /// The default is `false`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`State::take_pending_change`]: struct.State.html#method.take_pending_change
+    pub fn coalesce_messages(mut self, coalesce: bool) -> Self {
+        self.coalesce_messages = coalesce;
+        self
+    }
+
+    /// Sets the modifier keys that, while held during a drag gesture, let the
+    /// user scrub the [`HSlider`] without committing the value.
+    ///
+    /// While the keys are held the handle follows the cursor, but no
+    /// `on_change` messages are sent until the drag gesture is released (or
+    /// the keys are let go), at which point the latest value is sent once.
+    /// This is useful when each value change triggers an expensive
+    /// recomputation.
+    ///
+    /// By default no preview keys are set.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn preview_key(mut self, keys: keyboard::ModifiersState) -> Self {
+        self.preview_keys = Some(keys);
+        self
+    }
+
+    /// Sets whether a right click on the [`HSlider`] resets its value to
+    /// the default value.
+    ///
+    /// The value is reset when the right mouse button is pressed. A right
+    /// click during a drag gesture is ignored.
+    ///
+    /// The default is `true`. Note this differs from the `Knob`, `XYPad`,
+    /// `Ramp` and `ModRangeInput` widgets, where resetting on a right click
+    /// is opt-in.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn reset_on_right_click(mut self, reset: bool) -> Self {
+        self.reset_on_right_click = reset;
+        self
+    }
+
+    /// Sets the padding in pixels around the [`HSlider`] in which pressing
+    /// the mouse button will still begin dragging the slider. This makes thin
+    /// sliders easier to grab without changing their rendered size.
+    ///
+    /// With `DragMode::Absolute`, pressing within this padding of the handle
+    /// also grabs the handle instead of jumping it to the cursor.
+    ///
+    /// The default padding is `0`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn grab_padding(mut self, padding: u16) -> Self {
+        self.grab_padding = padding;
+        self
+    }
+
+    /// Overrides the width in pixels of the handle as it is drawn by the style
+    /// of the [`HSlider`].
+    ///
+    /// With `DragMode::Absolute` this is used to keep the handle from jumping
+    /// when it is grabbed: pressing on the handle itself begins a drag that
+    /// keeps the offset between the cursor and the handle center, and
+    /// pressing anywhere else moves the center of the handle to the cursor.
+    ///
+    /// By default the width of the handle drawn by the style is used, so this
+    /// only needs to be set when the renderer doesn't report the size of the
+    /// handle it draws. If set, it must match the drawn handle, or the cursor
+    /// will not line up with the handle.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn handle_width(mut self, width: u16) -> Self {
+        self.handle_width = Some(width);
+        self
+    }
+
+    /// Sets the padding in pixels between each end of the [`HSlider`] and the
+    /// center of the handle at the minimum and maximum values, in addition
+    /// to half the size of the handle.
+    ///
+    /// The padding is applied equally at both ends, so a value of `0.0` and
+    /// `1.0` place the handle the same distance from the left and right ends.
+    /// Tick marks and text marks are placed along the same padded range.
+    /// The segmented style has no handle and ignores this padding.
+    ///
+    /// The default padding is `0`.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn end_padding(mut self, padding: u16) -> Self {
+        self.end_padding = padding;
+        self
+    }
+
+    /// Sets the [`DragMode`] of the [`HSlider`], which determines whether
+    /// pressing the slider jumps the value to the cursor position or only
+    /// begins a relative drag gesture.
+    ///
+    /// The default mode is `DragMode::Relative`.
+    ///
+    /// [`DragMode`]: ../drag_mode/enum.DragMode.html
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn drag_mode(mut self, drag_mode: DragMode) -> Self {
+        self.drag_mode = drag_mode;
+        self
+    }
+
+    /// Sets the [`OverTravel`] behavior of the [`HSlider`], which determines
+    /// whether dragging past either end is accumulated or clamped.
+    ///
+    /// The default is `OverTravel::Accumulate`.
+    ///
+    /// [`OverTravel`]: ../over_travel/enum.OverTravel.html
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn over_travel(mut self, over_travel: OverTravel) -> Self {
+        self.over_travel = over_travel;
+        self
+    }
+
     /// Sets the tick marks to display. Note your [`StyleSheet`] must
     /// also implement `tick_marks_style(&self) -> Option<tick_marks::Style>` for
     /// them to display (which the default style does).
@@ -150,6 +435,69 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self
     }
 
+    /// Sets a function that formats the value of the [`HSlider`] for a
+    /// tooltip, which is displayed next to the cursor while the [`HSlider`] is
+    /// hovered or dragged. Note your [`StyleSheet`] must also implement
+    /// `tooltip_style(&self) -> Option<tooltip::Style>` for it to display
+    /// (which the default style does).
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`StyleSheet`]: ../../style/h_slider/trait.StyleSheet.html
+    pub fn tooltip<F>(mut self, tooltip: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> String,
+    {
+        self.tooltip = Some(Box::new(tooltip));
+        self
+    }
+
+    /// Sets a label that describes what the [`HSlider`] controls, such as
+    /// `"Cutoff"`, for assistive technology like screen readers.
+    ///
+    /// The label is not drawn. It is stored so it can be read back with
+    /// [`accessible_label`] to build an accessibility tree.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`accessible_label`]: struct.HSlider.html#method.accessible_label
+    pub fn accessibility_label(mut self, label: impl Into<String>) -> Self {
+        self.accessibility_label = Some(label.into());
+        self
+    }
+
+    /// Sets a function that describes the value of the [`HSlider`] in words
+    /// for assistive technology like screen readers, such as `"440 hertz"`.
+    ///
+    /// The description is not drawn. It can be read back with
+    /// [`accessible_value`] to build an accessibility tree.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`accessible_value`]: struct.HSlider.html#method.accessible_value
+    pub fn value_description<F>(mut self, value_description: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> String,
+    {
+        self.value_description = Some(Box::new(value_description));
+        self
+    }
+
+    /// Returns the label set with [`accessibility_label`], if any.
+    ///
+    /// [`accessibility_label`]: struct.HSlider.html#method.accessibility_label
+    pub fn accessible_label(&self) -> Option<&str> {
+        self.accessibility_label.as_deref()
+    }
+
+    /// Returns the description of the current value of the [`HSlider`] from the
+    /// function set with [`value_description`], if any.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    /// [`value_description`]: struct.HSlider.html#method.value_description
+    pub fn accessible_value(&self) -> Option<String> {
+        self.value_description.as_ref().map(|value_description| {
+            value_description(self.state.normal_param.value)
+        })
+    }
+
     /// Sets the text marks to display. Note your [`StyleSheet`] must
     /// also implement `text_marks_style(&self) -> Option<text_marks::Style>` for
     /// them to display (which the default style does).
@@ -181,6 +529,87 @@ impl<'a, Message, Renderer: self::Renderer> HSlider<'a, Message, Renderer> {
         self.mod_range_1 = Some(mod_range);
         self
     }
+
+    /// Sends the `on_gesture_end` message if the debounce after the last
+    /// release has elapsed without the widget being grabbed again.
+    fn push_gesture_end(&mut self, messages: &mut Vec<Message>) {
+        if let Some(released_at) = self.state.released_at {
+            if released_at.elapsed() >= self.gesture_end_debounce {
+                self.state.released_at = None;
+
+                if let Some(on_gesture_end) = &self.on_gesture_end {
+                    messages.push((on_gesture_end)());
+                }
+            }
+        }
+    }
+
+    /// Sends an `on_change` message for a value set by a drag gesture, or
+    /// holds it as pending if the preview keys are held, or if messages are
+    /// being coalesced and one was already sent in this frame.
+    fn push_drag_change(&mut self, messages: &mut Vec<Message>) {
+        if let Some(preview_keys) = self.preview_keys {
+            if self.state.pressed_modifiers.matches(preview_keys) {
+                self.state.pending_change = true;
+                return;
+            }
+        }
+
+        if self.coalesce_messages {
+            let now = Instant::now();
+
+            if let Some(last_change) = self.state.last_change {
+                if now.duration_since(last_change) < COALESCE_INTERVAL {
+                    self.state.pending_change = true;
+                    return;
+                }
+            }
+
+            self.state.last_change = Some(now);
+        }
+
+        self.state.pending_change = false;
+
+        messages.push((self.on_change)(self.state.normal_param.value));
+    }
+
+    /// Returns the distance in pixels from each end of the [`HSlider`] to the
+    /// center of the handle at the minimum and maximum values.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    fn handle_inset(&self, renderer: &Renderer) -> f32 {
+        (self.scaled_handle_width(renderer) / 2.0)
+            + (f32::from(self.end_padding) * self.scale)
+    }
+
+    /// Returns the scaled width in pixels of the handle, from the style
+    /// unless it was overridden with [`handle_width`].
+    ///
+    /// [`handle_width`]: #method.handle_width
+    fn scaled_handle_width(&self, renderer: &Renderer) -> f32 {
+        let handle_width = match self.handle_width {
+            Some(handle_width) => handle_width,
+            None => renderer.handle_width(&self.style),
+        };
+
+        f32::from(handle_width) * self.scale
+    }
+
+    /// Returns the modifier scalar for a cursor event that moved the cursor
+    /// by `pixels`, accelerated by the fine mode [`AccelCurve`] if one is set.
+    ///
+    /// [`AccelCurve`]: ../accel_curve/enum.AccelCurve.html
+    fn fine_scalar(&mut self, pixels: f32) -> f32 {
+        self.state.fine_drag_distance += pixels.abs();
+
+        match self.fine_mode_accel {
+            Some(accel) => {
+                self.modifier_scalar
+                    * accel.factor(self.state.fine_drag_distance, pixels)
+            }
+            None => self.modifier_scalar,
+        }
+    }
 }
 
 /// The local state of an [`HSlider`].
@@ -193,10 +622,16 @@ pub struct State {
     /// [`NormalParam`]: ../../core/normal_param/struct.Param.html
     pub normal_param: NormalParam,
     is_dragging: bool,
+    drag_anchor: Point,
     prev_drag_x: f32,
-    continuous_normal: f32,
+    continuous_normal: NormalFloat,
     pressed_modifiers: keyboard::ModifiersState,
+    fine_drag_distance: f32,
     last_click: Option<mouse::Click>,
+    is_focused: bool,
+    pending_change: bool,
+    last_change: Option<Instant>,
+    released_at: Option<Instant>,
 }
 
 impl State {
@@ -211,12 +646,95 @@ impl State {
         Self {
             normal_param,
             is_dragging: false,
+            drag_anchor: Point::ORIGIN,
             prev_drag_x: 0.0,
-            continuous_normal: normal_param.value.as_f32(),
+            continuous_normal: normal_param.value.value(),
             pressed_modifiers: Default::default(),
+            fine_drag_distance: 0.0,
             last_click: None,
+            is_focused: false,
+            pending_change: false,
+            last_change: None,
+            released_at: None,
+        }
+    }
+
+    /// Returns the latest value set by a drag gesture that has not been sent
+    /// as a message yet because [`coalesce_messages`] is enabled or the
+    /// preview keys are held, and clears it.
+    ///
+    /// Call this once per frame (for example from a timer subscription) to
+    /// flush the pending value without waiting for the next cursor movement
+    /// or the release of the drag gesture.
+    ///
+    /// [`coalesce_messages`]: struct.HSlider.html#method.coalesce_messages
+    pub fn take_pending_change(&mut self) -> Option<Normal> {
+        if self.pending_change {
+            self.pending_change = false;
+            Some(self.normal_param.value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the position of the cursor where the current drag gesture
+    /// started, or `None` if the [`HSlider`] is not being dragged.
+    ///
+    /// This can be used to draw a custom cursor that stays anchored to the
+    /// point where the [`HSlider`] was grabbed.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn drag_anchor(&self) -> Option<Point> {
+        if self.is_dragging {
+            Some(self.drag_anchor)
+        } else {
+            None
         }
     }
+
+    /// Returns the current [`Normal`] value of the [`HSlider`].
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn normal(&self) -> Normal {
+        self.normal_param.value
+    }
+
+    /// Sets the current [`Normal`] value of the [`HSlider`].
+    ///
+    /// This does not send a message.
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn set_normal(&mut self, normal: Normal) {
+        self.normal_param.value = normal;
+        self.continuous_normal = normal.value();
+    }
+
+    /// Returns whether the [`HSlider`] has keyboard focus.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Gives keyboard focus to the [`HSlider`], so that it responds to the
+    /// arrow keys.
+    ///
+    /// Clicking the [`HSlider`] also gives it focus, and clicking outside of
+    /// it removes focus.
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Removes keyboard focus from the [`HSlider`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -250,46 +768,80 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
         messages: &mut Vec<Message>,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
     ) {
+        self.push_gesture_end(messages);
+
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
                     if self.state.is_dragging {
-                        let bounds_width = layout.bounds().width;
+                        let is_absolute = self.drag_mode == DragMode::Absolute;
 
-                        if bounds_width > 0.0 {
+                        // In absolute mode the handle follows the cursor over
+                        // the same travel used when it was grabbed.
+                        let travel = if is_absolute {
+                            layout.bounds().width
+                                - (self.handle_inset(renderer) * 2.0)
+                        } else {
+                            layout.bounds().width
+                        };
+
+                        if travel > 0.0 {
                             let mut movement_x = (cursor_position.x
                                 - self.state.prev_drag_x)
-                                / bounds_width;
+                                / travel;
 
                             if self
                                 .state
                                 .pressed_modifiers
                                 .matches(self.modifier_keys)
                             {
-                                movement_x *= self.modifier_scalar;
-                            } else {
+                                movement_x *= self.fine_scalar(
+                                    cursor_position.x - self.state.prev_drag_x,
+                                );
+                            } else if !is_absolute {
                                 movement_x *= self.scalar;
                             }
 
-                            let normal =
-                                self.state.continuous_normal + movement_x;
+                            let mut normal = self.state.continuous_normal
+                                + movement_x as NormalFloat;
+
+                            if self.over_travel == OverTravel::Clamp {
+                                if normal < 0.0 {
+                                    normal = 0.0;
+                                } else if normal > 1.0 {
+                                    normal = 1.0;
+                                }
+                            }
 
                             self.state.continuous_normal = normal;
                             self.state.prev_drag_x = cursor_position.x;
 
-                            self.state.normal_param.value = normal.into();
+                            self.state.normal_param.value =
+                                Normal::from_float(normal);
 
-                            messages.push((self.on_change)(
-                                self.state.normal_param.value,
-                            ));
+                            self.push_drag_change(messages);
                         }
                     }
                 }
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
-                    if layout.bounds().contains(cursor_position) {
+                    let grab_bounds = {
+                        let bounds = layout.bounds();
+                        let padding = f32::from(self.grab_padding);
+
+                        Rectangle {
+                            x: bounds.x - padding,
+                            y: bounds.y - padding,
+                            width: bounds.width + (padding * 2.0),
+                            height: bounds.height + (padding * 2.0),
+                        }
+                    };
+
+                    if grab_bounds.contains(cursor_position) {
+                        self.state.is_focused = true;
+
                         let click = mouse::Click::new(
                             cursor_position,
                             self.state.last_click,
@@ -298,7 +850,58 @@ where
                         match click.kind() {
                             mouse::click::Kind::Single => {
                                 self.state.is_dragging = true;
+                                self.state.fine_drag_distance = 0.0;
+                                self.state.released_at = None;
+                                self.state.drag_anchor = cursor_position;
                                 self.state.prev_drag_x = cursor_position.x;
+
+                                if let Some(on_grab) = &self.on_grab {
+                                    messages.push((on_grab)());
+                                }
+
+                                let bounds = layout.bounds();
+
+                                let handle_width =
+                                    self.scaled_handle_width(renderer);
+                                let handle_inset = self.handle_inset(renderer);
+                                let travel =
+                                    bounds.width - (handle_inset * 2.0);
+
+                                if self.drag_mode == DragMode::Absolute
+                                    && travel > 0.0
+                                {
+                                    let handle_center = bounds.x
+                                        + handle_inset
+                                        + self
+                                            .state
+                                            .normal_param
+                                            .value
+                                            .scale(travel);
+
+                                    let grab_offset =
+                                        cursor_position.x - handle_center;
+
+                                    // Grabbing the handle itself, or within
+                                    // the grab padding of it, keeps the grab
+                                    // offset instead of jumping.
+                                    if grab_offset.abs()
+                                        > (handle_width / 2.0)
+                                            + f32::from(self.grab_padding)
+                                    {
+                                        let normal: Normal =
+                                            ((cursor_position.x
+                                                - bounds.x
+                                                - handle_inset)
+                                                / travel)
+                                                .into();
+
+                                        self.state.continuous_normal =
+                                            normal.value();
+                                        self.state.normal_param.value = normal;
+
+                                        self.push_drag_change(messages);
+                                    }
+                                }
                             }
                             _ => {
                                 self.state.is_dragging = false;
@@ -313,21 +916,98 @@ where
                         }
 
                         self.state.last_click = Some(click);
+                    } else {
+                        self.state.is_focused = false;
+                    }
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Right) => {
+                    // A right click during a drag gesture is ignored, so the
+                    // gesture still ends with its release messages.
+                    if self.reset_on_right_click
+                        && !self.state.is_dragging
+                        && layout.bounds().contains(cursor_position)
+                    {
+                        self.state.normal_param.value =
+                            self.state.normal_param.default;
+                        self.state.continuous_normal =
+                            self.state.normal_param.value.value();
+
+                        messages.push((self.on_change)(
+                            self.state.normal_param.value,
+                        ));
                     }
                 }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if self.state.is_dragging {
+                        if self.state.pending_change {
+                            self.state.pending_change = false;
+
+                            messages.push((self.on_change)(
+                                self.state.normal_param.value,
+                            ));
+                        }
+
+                        self.state.released_at = Some(Instant::now());
+
+                        if let Some(on_release) = &self.on_release {
+                            messages.push((on_release)());
+                        }
+                    }
+
                     self.state.is_dragging = false;
+                    self.state.last_change = None;
                     self.state.continuous_normal =
-                        self.state.normal_param.value.as_f32();
+                        self.state.normal_param.value.value();
                 }
                 _ => {}
             },
             Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
+                keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                } => {
                     self.state.pressed_modifiers = modifiers;
+
+                    if self.state.is_focused {
+                        let step = match key_code {
+                            keyboard::KeyCode::Right
+                            | keyboard::KeyCode::Up => Some(self.keyboard_step),
+                            keyboard::KeyCode::Left
+                            | keyboard::KeyCode::Down => {
+                                Some(-self.keyboard_step)
+                            }
+                            _ => None,
+                        };
+
+                        if let Some(step) = step {
+                            let normal =
+                                self.state.normal_param.value.clamped_add(step);
+
+                            self.state.continuous_normal = normal.value();
+                            self.state.normal_param.value = normal;
+
+                            messages.push((self.on_change)(
+                                self.state.normal_param.value,
+                            ));
+                        }
+                    }
                 }
                 keyboard::Event::KeyReleased { modifiers, .. } => {
                     self.state.pressed_modifiers = modifiers;
+
+                    // Send the previewed value once the preview keys are
+                    // let go.
+                    if let Some(preview_keys) = self.preview_keys {
+                        if self.state.pending_change
+                            && !modifiers.matches(preview_keys)
+                        {
+                            self.state.pending_change = false;
+
+                            messages.push((self.on_change)(
+                                self.state.normal_param.value,
+                            ));
+                        }
+                    }
                 }
                 _ => {}
             },
@@ -342,15 +1022,29 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
+        let tooltip = match &self.tooltip {
+            Some(tooltip)
+                if self.state.is_dragging
+                    || layout.bounds().contains(cursor_position) =>
+            {
+                Some(tooltip(self.state.normal_param.value))
+            }
+            _ => None,
+        };
+
         renderer.draw(
             layout.bounds(),
             cursor_position,
             self.state.normal_param.value,
             self.state.is_dragging,
+            self.state.is_focused,
             self.mod_range_1,
             self.mod_range_2,
             self.tick_marks,
             self.text_marks,
+            tooltip.as_deref(),
+            self.end_padding,
+            self.scale,
             &self.style,
         )
     }
@@ -361,6 +1055,7 @@ where
 
         self.width.hash(state);
         self.height.hash(state);
+        self.layout_key.hash(state);
     }
 }
 
@@ -374,6 +1069,16 @@ pub trait Renderer: iced_native::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
+    /// Returns the width in pixels of the handle drawn for the given style
+    /// of an [`HSlider`], before it is scaled.
+    ///
+    /// This is used to grab the handle with `DragMode::Absolute`. A style
+    /// that draws no handle returns `0`, which maps the cursor to the full
+    /// width of the [`HSlider`].
+    ///
+    /// [`HSlider`]: struct.HSlider.html
+    fn handle_width(&self, style: &Self::Style) -> u16;
+
     /// Draws an [`HSlider`].
     ///
     /// It receives:
@@ -382,8 +1087,12 @@ pub trait Renderer: iced_native::Renderer {
     ///   * the current normal of the [`HSlider`]
     ///   * the height of the handle in pixels
     ///   * whether the slider is currently being dragged
+    ///   * whether the slider has keyboard focus
     ///   * any tick marks to display
     ///   * any text marks to display
+    ///   * the text of the value tooltip, if it should be displayed
+    ///   * the padding between each end and the handle at the end values
+    ///   * the factor all sizes in the style are multiplied by
     ///   * the style of the [`HSlider`]
     ///
     /// [`HSlider`]: struct.HSlider.html
@@ -393,10 +1102,14 @@ pub trait Renderer: iced_native::Renderer {
         cursor_position: Point,
         normal: Normal,
         is_dragging: bool,
+        is_focused: bool,
         mod_range_1: Option<&ModulationRange>,
         mod_range_2: Option<&ModulationRange>,
         tick_marks: Option<&tick_marks::Group>,
         text_marks: Option<&text_marks::Group>,
+        tooltip: Option<&str>,
+        end_padding: u16,
+        scale: f32,
         style: &Self::Style,
     ) -> Self::Output;
 }
@@ -10,13 +10,17 @@ use iced_native::{
 };
 
 use std::hash::Hash;
+use std::time::{Duration, Instant};
 
-use crate::core::{ModulationRange, Normal, NormalParam};
-use crate::native::{text_marks, tick_marks};
+use crate::core::{ModulationRange, Normal, NormalFloat, NormalParam};
+use crate::native::{text_marks, tick_marks, AccelCurve};
 
 static DEFAULT_SIZE: u16 = 30;
 static DEFAULT_SCALAR: f32 = 0.005;
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
+static DEFAULT_KEYBOARD_STEP: f32 = 0.01;
+static COALESCE_INTERVAL: Duration = Duration::from_millis(16);
+static DEFAULT_GESTURE_END_DEBOUNCE: Duration = Duration::from_millis(200);
 
 /// A rotating knob GUI widget that controls a [`NormalParam`]
 ///
@@ -26,10 +30,27 @@ pub struct Knob<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     size: Length,
     on_change: Box<dyn Fn(Normal) -> Message>,
+    on_grab: Option<Box<dyn Fn() -> Message>>,
+    on_release: Option<Box<dyn Fn() -> Message>>,
+    on_gesture_end: Option<Box<dyn Fn() -> Message>>,
+    on_delta: Option<Box<dyn Fn(f32) -> Message>>,
+    gesture_end_debounce: Duration,
+    tooltip: Option<Box<dyn Fn(Normal) -> String>>,
+    accessibility_label: Option<String>,
+    value_description: Option<Box<dyn Fn(Normal) -> String>>,
+    value_text: Option<Box<dyn Fn(Normal) -> String>>,
     scalar: f32,
     modifier_scalar: f32,
+    fine_mode_accel: Option<AccelCurve>,
     modifier_keys: keyboard::ModifiersState,
+    keyboard_step: f32,
+    coalesce_messages: bool,
+    preview_keys: Option<keyboard::ModifiersState>,
+    reset_on_right_click: bool,
+    endless: bool,
+    value_bounds: (Normal, Normal),
     style: Renderer::Style,
+    layout_key: Option<u64>,
     tick_marks: Option<&'a tick_marks::Group>,
     text_marks: Option<&'a text_marks::Group>,
     mod_range_1: Option<&'a ModulationRange>,
@@ -53,13 +74,30 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
             state,
             size: Length::from(Length::Units(DEFAULT_SIZE)),
             on_change: Box::new(on_change),
+            on_grab: None,
+            on_release: None,
+            on_gesture_end: None,
+            on_delta: None,
+            gesture_end_debounce: DEFAULT_GESTURE_END_DEBOUNCE,
+            tooltip: None,
+            accessibility_label: None,
+            value_description: None,
+            value_text: None,
             scalar: DEFAULT_SCALAR,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            fine_mode_accel: None,
             modifier_keys: keyboard::ModifiersState {
                 control: true,
                 ..Default::default()
             },
+            keyboard_step: DEFAULT_KEYBOARD_STEP,
+            coalesce_messages: false,
+            preview_keys: None,
+            reset_on_right_click: false,
+            endless: false,
+            value_bounds: (Normal::min(), Normal::max()),
             style: Renderer::Style::default(),
+            layout_key: None,
             tick_marks: None,
             text_marks: None,
             mod_range_1: None,
@@ -67,6 +105,90 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         }
     }
 
+    /// Sets a function that will be called when the user grabs the
+    /// [`Knob`] to begin a drag gesture.
+    ///
+    /// This message is sent independently of the value change messages,
+    /// so it can be used to begin a gesture such as writing host automation.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn on_grab<F>(mut self, on_grab: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets a function that will be called when the user releases
+    /// a [`Knob`] that was being dragged.
+    ///
+    /// This message is sent independently of the value change messages,
+    /// so it can be used to end a gesture such as writing host automation.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn on_release<F>(mut self, on_release: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
+    /// Sets a function that will be called when a drag gesture on the
+    /// [`Knob`] has ended.
+    ///
+    /// Unlike `on_release`, this message is only sent once the [`Knob`]
+    /// has been released and not grabbed again for the duration set with
+    /// [`gesture_end_debounce`]. A quick re-grab continues the same gesture,
+    /// which avoids spurious begin/end pairs when writing host automation.
+    ///
+    /// Widgets only run when they receive events, so the message is sent
+    /// with the first event (such as a cursor movement) that arrives after
+    /// the debounce has elapsed.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`gesture_end_debounce`]: struct.Knob.html#method.gesture_end_debounce
+    pub fn on_gesture_end<F>(mut self, on_gesture_end: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_gesture_end = Some(Box::new(on_gesture_end));
+        self
+    }
+
+    /// Sets a function that will be called with the relative change of the
+    /// value when an [`endless`] [`Knob`] is turned.
+    ///
+    /// The change is the amount the [`Normal`] value moved before it was
+    /// wrapped, so a full turn of the [`Knob`] adds up to `1.0` (or `-1.0`).
+    /// This is useful for parameters such as a continuously rotating encoder
+    /// that only care about how far the [`Knob`] was turned. The deltas are
+    /// always sent, even while `on_change` messages are coalesced or held by
+    /// the preview keys.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`endless`]: struct.Knob.html#method.endless
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn on_delta<F>(mut self, on_delta: F) -> Self
+    where
+        F: 'static + Fn(f32) -> Message,
+    {
+        self.on_delta = Some(Box::new(on_delta));
+        self
+    }
+
+    /// Sets how long the [`Knob`] must stay released before the
+    /// `on_gesture_end` message is sent.
+    ///
+    /// The default debounce is `200` milliseconds.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn gesture_end_debounce(mut self, debounce: Duration) -> Self {
+        self.gesture_end_debounce = debounce;
+        self
+    }
+
     /// Sets the diameter of the [`Knob`]. The default size is
     /// `Length::from(Length::Units(31))`.
     ///
@@ -84,6 +206,18 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
+    /// Sets a key that is included in the layout hash of the [`Knob`].
+    ///
+    /// Changing the key forces a relayout, which is useful when the style or
+    /// textures are swapped at runtime in a way that changes how the
+    /// [`Knob`] should be laid out.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn layout_key(mut self, key: u64) -> Self {
+        self.layout_key = Some(key);
+        self
+    }
+
     /// Sets how much the [`Normal`] value will change for the [`Knob`] per `y`
     /// pixel movement of the mouse.
     ///
@@ -125,6 +259,123 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
+    /// Sets an [`AccelCurve`] that increases the modifier scalar during a
+    /// drag gesture, either with the distance dragged while holding the
+    /// modifier keys or with the speed of the cursor.
+    ///
+    /// The default is `None`, which uses the constant modifier scalar.
+    ///
+    /// [`AccelCurve`]: ../accel_curve/enum.AccelCurve.html
+    pub fn fine_mode_accel(mut self, accel: Option<AccelCurve>) -> Self {
+        self.fine_mode_accel = accel;
+        self
+    }
+
+    /// Sets the amount the value of the [`Knob`] changes when the arrow
+    /// keys are pressed while it has keyboard focus.
+    ///
+    /// The default step is `0.01`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn keyboard_step(mut self, step: f32) -> Self {
+        self.keyboard_step = step;
+        self
+    }
+
+    /// Sets whether the [`Knob`] coalesces the messages it sends while
+    /// being dragged.
+    ///
+    /// When enabled, a fast drag sends at most one `on_change` message per
+    /// frame (about 16 ms) instead of one per cursor movement, which keeps the
+    /// update loop from being flooded. The latest value is always sent when
+    /// the drag gesture is released, and it can be flushed sooner with
+    /// [`State::take_pending_change`].
+    ///
+    /// Note this lowers the resolution of the values the application
+    /// receives, so automation recorded from these messages will have fewer
+    /// points and may miss short movements between frames. Leave this
+    /// disabled when recording host automation at full resolution.
+    ///
+    /// The default is `false`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`State::take_pending_change`]: struct.State.html#method.take_pending_change
+    pub fn coalesce_messages(mut self, coalesce: bool) -> Self {
+        self.coalesce_messages = coalesce;
+        self
+    }
+
+    /// Sets the modifier keys that, while held during a drag gesture, let the
+    /// user scrub the [`Knob`] without committing the value.
+    ///
+    /// While the keys are held the handle follows the cursor, but no
+    /// `on_change` messages are sent until the drag gesture is released (or
+    /// the keys are let go), at which point the latest value is sent once.
+    /// This is useful when each value change triggers an expensive
+    /// recomputation.
+    ///
+    /// By default no preview keys are set.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn preview_key(mut self, keys: keyboard::ModifiersState) -> Self {
+        self.preview_keys = Some(keys);
+        self
+    }
+
+    /// Sets whether a right click on the [`Knob`] resets its value to
+    /// the default value.
+    ///
+    /// The value is reset when the right mouse button is released without
+    /// the cursor having moved since it was pressed. A right click during a
+    /// drag gesture is ignored.
+    ///
+    /// The default is `false`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn reset_on_right_click(mut self, reset: bool) -> Self {
+        self.reset_on_right_click = reset;
+        self
+    }
+
+    /// Sets whether the [`Knob`] rotates endlessly with no hard stops.
+    ///
+    /// In endless mode, turning the [`Knob`] past the maximum value wraps
+    /// around to the minimum value (and vice versa), the [`Knob`] rotates
+    /// through the full circle regardless of the angle range of the style,
+    /// and the value arc is not drawn. Use [`on_delta`] to receive the
+    /// relative change of each movement. The value bounds are ignored.
+    ///
+    /// The default is `false`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`on_delta`]: struct.Knob.html#method.on_delta
+    pub fn endless(mut self, endless: bool) -> Self {
+        self.endless = endless;
+        self
+    }
+
+    /// Limits the [`Normal`] values the user can set by interacting with the
+    /// [`Knob`] to the range between `min` and `max` (inclusive). Dragging
+    /// past a limit will keep the [`Knob`] pinned at that limit.
+    ///
+    /// Tick marks, text marks, and arcs are still drawn over the full range,
+    /// so the limits are visible to the user.
+    ///
+    /// The default bounds are `Normal::min()` and `Normal::max()`.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if `min` > `max`.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn value_bounds(mut self, min: Normal, max: Normal) -> Self {
+        assert!(min <= max);
+
+        self.value_bounds = (min, max);
+        self
+    }
+
     /// Sets the tick marks to display. Note your [`StyleSheet`] must
     /// also implement `tick_marks_style(&self) -> Option<tick_marks::Style>` for
     /// them to display (which the default style does).
@@ -135,6 +386,84 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self
     }
 
+    /// Sets a function that formats the value of the [`Knob`] for a
+    /// tooltip, which is displayed next to the cursor while the [`Knob`] is
+    /// hovered or dragged. Note your [`StyleSheet`] must also implement
+    /// `tooltip_style(&self) -> Option<tooltip::Style>` for it to display
+    /// (which the default style does).
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`StyleSheet`]: ../../style/knob/trait.StyleSheet.html
+    pub fn tooltip<F>(mut self, tooltip: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> String,
+    {
+        self.tooltip = Some(Box::new(tooltip));
+        self
+    }
+
+    /// Sets a label that describes what the [`Knob`] controls, such as
+    /// `"Cutoff"`, for assistive technology like screen readers.
+    ///
+    /// The label is not drawn. It is stored so it can be read back with
+    /// [`accessible_label`] to build an accessibility tree.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`accessible_label`]: struct.Knob.html#method.accessible_label
+    pub fn accessibility_label(mut self, label: impl Into<String>) -> Self {
+        self.accessibility_label = Some(label.into());
+        self
+    }
+
+    /// Sets a function that describes the value of the [`Knob`] in words
+    /// for assistive technology like screen readers, such as `"440 hertz"`.
+    ///
+    /// The description is not drawn. It can be read back with
+    /// [`accessible_value`] to build an accessibility tree.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`accessible_value`]: struct.Knob.html#method.accessible_value
+    pub fn value_description<F>(mut self, value_description: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> String,
+    {
+        self.value_description = Some(Box::new(value_description));
+        self
+    }
+
+    /// Returns the label set with [`accessibility_label`], if any.
+    ///
+    /// [`accessibility_label`]: struct.Knob.html#method.accessibility_label
+    pub fn accessible_label(&self) -> Option<&str> {
+        self.accessibility_label.as_deref()
+    }
+
+    /// Returns the description of the current value of the [`Knob`] from the
+    /// function set with [`value_description`], if any.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`value_description`]: struct.Knob.html#method.value_description
+    pub fn accessible_value(&self) -> Option<String> {
+        self.value_description.as_ref().map(|value_description| {
+            value_description(self.state.normal_param.value)
+        })
+    }
+
+    /// Sets a function that formats the value of the [`Knob`] for a readout
+    /// that is displayed in the center of the [`Knob`]. Note your
+    /// [`StyleSheet`] must also implement
+    /// `center_text_style(&self) -> Option<CenterTextStyle>` for it to display.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    /// [`StyleSheet`]: ../../style/knob/trait.StyleSheet.html
+    pub fn value_text<F>(mut self, value_text: F) -> Self
+    where
+        F: 'static + Fn(Normal) -> String,
+    {
+        self.value_text = Some(Box::new(value_text));
+        self
+    }
+
     /// Sets the text marks to display. Note your [`StyleSheet`] must
     /// also implement `text_marks_style(&self) -> Option<text_marks::Style>` for
     /// them to display (which the default style does).
@@ -166,6 +495,100 @@ impl<'a, Message, Renderer: self::Renderer> Knob<'a, Message, Renderer> {
         self.mod_range_1 = Some(mod_range);
         self
     }
+
+    /// Sends the `on_gesture_end` message if the debounce after the last
+    /// release has elapsed without the widget being grabbed again.
+    fn push_gesture_end(&mut self, messages: &mut Vec<Message>) {
+        if let Some(released_at) = self.state.released_at {
+            if released_at.elapsed() >= self.gesture_end_debounce {
+                self.state.released_at = None;
+
+                if let Some(on_gesture_end) = &self.on_gesture_end {
+                    messages.push((on_gesture_end)());
+                }
+            }
+        }
+    }
+
+    /// Sends an `on_change` message for a value set by a drag gesture, or
+    /// holds it as pending if the preview keys are held, or if messages are
+    /// being coalesced and one was already sent in this frame.
+    fn push_drag_change(&mut self, messages: &mut Vec<Message>) {
+        if let Some(preview_keys) = self.preview_keys {
+            if self.state.pressed_modifiers.matches(preview_keys) {
+                self.state.pending_change = true;
+                return;
+            }
+        }
+
+        if self.coalesce_messages {
+            let now = Instant::now();
+
+            if let Some(last_change) = self.state.last_change {
+                if now.duration_since(last_change) < COALESCE_INTERVAL {
+                    self.state.pending_change = true;
+                    return;
+                }
+            }
+
+            self.state.last_change = Some(now);
+        }
+
+        self.state.pending_change = false;
+
+        messages.push((self.on_change)(self.state.normal_param.value));
+    }
+
+    /// Sends an `on_delta` message with the relative change of the value if
+    /// the [`Knob`] is endless.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    fn push_delta(&self, delta: f32, messages: &mut Vec<Message>) {
+        if self.endless && delta != 0.0 {
+            if let Some(on_delta) = &self.on_delta {
+                messages.push((on_delta)(delta));
+            }
+        }
+    }
+
+    /// Ends any drag gesture and resets the value to the default value,
+    /// clamped to the value bounds.
+    fn reset_to_default(&mut self, messages: &mut Vec<Message>) {
+        self.state.is_dragging = false;
+
+        let (min, max) = self.value_bounds;
+
+        self.state.normal_param.value = {
+            let default = self.state.normal_param.default;
+
+            if default < min {
+                min
+            } else if default > max {
+                max
+            } else {
+                default
+            }
+        };
+        self.state.continuous_normal = self.state.normal_param.value.value();
+
+        messages.push((self.on_change)(self.state.normal_param.value));
+    }
+
+    /// Returns the modifier scalar for a cursor event that moved the cursor
+    /// by `pixels`, accelerated by the fine mode [`AccelCurve`] if one is set.
+    ///
+    /// [`AccelCurve`]: ../accel_curve/enum.AccelCurve.html
+    fn fine_scalar(&mut self, pixels: f32) -> f32 {
+        self.state.fine_drag_distance += pixels.abs();
+
+        match self.fine_mode_accel {
+            Some(accel) => {
+                self.modifier_scalar
+                    * accel.factor(self.state.fine_drag_distance, pixels)
+            }
+            None => self.modifier_scalar,
+        }
+    }
 }
 
 /// The local state of a [`Knob`].
@@ -178,10 +601,17 @@ pub struct State {
     /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
     pub normal_param: NormalParam,
     is_dragging: bool,
+    drag_anchor: Point,
     prev_drag_y: f32,
-    continuous_normal: f32,
+    continuous_normal: NormalFloat,
     pressed_modifiers: keyboard::ModifiersState,
+    fine_drag_distance: f32,
     last_click: Option<mouse::Click>,
+    right_pressed_at: Option<Point>,
+    is_focused: bool,
+    pending_change: bool,
+    last_change: Option<Instant>,
+    released_at: Option<Instant>,
 }
 
 impl State {
@@ -196,12 +626,96 @@ impl State {
         Self {
             normal_param,
             is_dragging: false,
+            drag_anchor: Point::ORIGIN,
             prev_drag_y: 0.0,
-            continuous_normal: normal_param.value.as_f32(),
+            continuous_normal: normal_param.value.value(),
             pressed_modifiers: Default::default(),
+            fine_drag_distance: 0.0,
             last_click: None,
+            right_pressed_at: None,
+            is_focused: false,
+            pending_change: false,
+            last_change: None,
+            released_at: None,
+        }
+    }
+
+    /// Returns the latest value set by a drag gesture that has not been sent
+    /// as a message yet because [`coalesce_messages`] is enabled or the
+    /// preview keys are held, and clears it.
+    ///
+    /// Call this once per frame (for example from a timer subscription) to
+    /// flush the pending value without waiting for the next cursor movement
+    /// or the release of the drag gesture.
+    ///
+    /// [`coalesce_messages`]: struct.Knob.html#method.coalesce_messages
+    pub fn take_pending_change(&mut self) -> Option<Normal> {
+        if self.pending_change {
+            self.pending_change = false;
+            Some(self.normal_param.value)
+        } else {
+            None
         }
     }
+
+    /// Returns the position of the cursor where the current drag gesture
+    /// started, or `None` if the [`Knob`] is not being dragged.
+    ///
+    /// This can be used to draw a custom cursor that stays anchored to the
+    /// point where the [`Knob`] was grabbed.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn drag_anchor(&self) -> Option<Point> {
+        if self.is_dragging {
+            Some(self.drag_anchor)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the current [`Normal`] value of the [`Knob`].
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    /// [`Knob`]: struct.Knob.html
+    pub fn normal(&self) -> Normal {
+        self.normal_param.value
+    }
+
+    /// Sets the current [`Normal`] value of the [`Knob`].
+    ///
+    /// This does not send a message.
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    /// [`Knob`]: struct.Knob.html
+    pub fn set_normal(&mut self, normal: Normal) {
+        self.normal_param.value = normal;
+        self.continuous_normal = normal.value();
+    }
+
+    /// Returns whether the [`Knob`] has keyboard focus.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Gives keyboard focus to the [`Knob`], so that it responds to the
+    /// arrow keys.
+    ///
+    /// Clicking the [`Knob`] also gives it focus, and clicking outside of
+    /// it removes focus.
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Removes keyboard focus from the [`Knob`].
+    ///
+    /// [`Knob`]: struct.Knob.html
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -238,6 +752,8 @@ where
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
     ) {
+        self.push_gesture_end(messages);
+
         match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
@@ -251,30 +767,40 @@ where
                             .pressed_modifiers
                             .matches(self.modifier_keys)
                         {
-                            movement_y *= self.modifier_scalar;
+                            movement_y *= self.fine_scalar(
+                                cursor_position.y - self.state.prev_drag_y,
+                            );
                         }
 
-                        let mut normal =
-                            self.state.continuous_normal - movement_y;
+                        let mut normal = self.state.continuous_normal
+                            - movement_y as NormalFloat;
+
+                        if self.endless {
+                            normal = normal.rem_euclid(1.0);
+                        } else {
+                            let (min, max) = self.value_bounds;
 
-                        if normal < 0.0 {
-                            normal = 0.0;
-                        } else if normal > 1.0 {
-                            normal = 1.0;
+                            if normal < min.value() {
+                                normal = min.value();
+                            } else if normal > max.value() {
+                                normal = max.value();
+                            }
                         }
 
                         self.state.continuous_normal = normal;
                         self.state.prev_drag_y = cursor_position.y;
 
-                        self.state.normal_param.value = normal.into();
+                        self.state.normal_param.value =
+                            Normal::from_float(normal);
 
-                        messages.push((self.on_change)(
-                            self.state.normal_param.value,
-                        ));
+                        self.push_drag_change(messages);
+                        self.push_delta(-movement_y, messages);
                     }
                 }
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
                     if layout.bounds().contains(cursor_position) {
+                        self.state.is_focused = true;
+
                         let click = mouse::Click::new(
                             cursor_position,
                             self.state.last_click,
@@ -283,36 +809,129 @@ where
                         match click.kind() {
                             mouse::click::Kind::Single => {
                                 self.state.is_dragging = true;
+                                self.state.fine_drag_distance = 0.0;
+                                self.state.released_at = None;
+                                self.state.drag_anchor = cursor_position;
                                 self.state.prev_drag_y = cursor_position.y;
+
+                                if let Some(on_grab) = &self.on_grab {
+                                    messages.push((on_grab)());
+                                }
                             }
                             _ => {
-                                self.state.is_dragging = false;
-
-                                self.state.normal_param.value =
-                                    self.state.normal_param.default;
-
-                                messages.push((self.on_change)(
-                                    self.state.normal_param.value,
-                                ));
+                                self.reset_to_default(messages);
                             }
                         }
 
                         self.state.last_click = Some(click);
+                    } else {
+                        self.state.is_focused = false;
+                    }
+                }
+                mouse::Event::ButtonPressed(mouse::Button::Right) => {
+                    // A right click during a drag gesture is ignored, so the
+                    // gesture still ends with its release messages.
+                    if self.reset_on_right_click
+                        && !self.state.is_dragging
+                        && layout.bounds().contains(cursor_position)
+                    {
+                        self.state.right_pressed_at = Some(cursor_position);
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Right) => {
+                    if let Some(pressed_at) = self.state.right_pressed_at.take()
+                    {
+                        // Only a click without cursor movement resets.
+                        if pressed_at == cursor_position
+                            && !self.state.is_dragging
+                        {
+                            self.reset_to_default(messages);
+                        }
                     }
                 }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if self.state.is_dragging {
+                        if self.state.pending_change {
+                            self.state.pending_change = false;
+
+                            messages.push((self.on_change)(
+                                self.state.normal_param.value,
+                            ));
+                        }
+
+                        self.state.released_at = Some(Instant::now());
+
+                        if let Some(on_release) = &self.on_release {
+                            messages.push((on_release)());
+                        }
+                    }
+
                     self.state.is_dragging = false;
+                    self.state.last_change = None;
                     self.state.continuous_normal =
-                        self.state.normal_param.value.as_f32();
+                        self.state.normal_param.value.value();
                 }
                 _ => {}
             },
             Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::KeyPressed { modifiers, .. } => {
+                keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                } => {
                     self.state.pressed_modifiers = modifiers;
+
+                    if self.state.is_focused {
+                        let step = match key_code {
+                            keyboard::KeyCode::Right
+                            | keyboard::KeyCode::Up => Some(self.keyboard_step),
+                            keyboard::KeyCode::Left
+                            | keyboard::KeyCode::Down => {
+                                Some(-self.keyboard_step)
+                            }
+                            _ => None,
+                        };
+
+                        if let Some(step) = step {
+                            let (min, max) = self.value_bounds;
+
+                            let normal =
+                                self.state.normal_param.value.as_f32() + step;
+
+                            let normal: Normal = if self.endless {
+                                normal.rem_euclid(1.0).into()
+                            } else {
+                                normal
+                                    .max(min.as_f32())
+                                    .min(max.as_f32())
+                                    .into()
+                            };
+
+                            self.state.continuous_normal = normal.value();
+                            self.state.normal_param.value = normal;
+
+                            messages.push((self.on_change)(
+                                self.state.normal_param.value,
+                            ));
+                            self.push_delta(step, messages);
+                        }
+                    }
                 }
                 keyboard::Event::KeyReleased { modifiers, .. } => {
                     self.state.pressed_modifiers = modifiers;
+
+                    // Send the previewed value once the preview keys are
+                    // let go.
+                    if let Some(preview_keys) = self.preview_keys {
+                        if self.state.pending_change
+                            && !modifiers.matches(preview_keys)
+                        {
+                            self.state.pending_change = false;
+
+                            messages.push((self.on_change)(
+                                self.state.normal_param.value,
+                            ));
+                        }
+                    }
                 }
                 _ => {}
             },
@@ -327,15 +946,34 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
+        let tooltip = match &self.tooltip {
+            Some(tooltip)
+                if self.state.is_dragging
+                    || layout.bounds().contains(cursor_position) =>
+            {
+                Some(tooltip(self.state.normal_param.value))
+            }
+            _ => None,
+        };
+
+        let value_text = self
+            .value_text
+            .as_ref()
+            .map(|value_text| value_text(self.state.normal_param.value));
+
         renderer.draw(
             layout.bounds(),
             cursor_position,
             self.state.normal_param.value,
             self.state.is_dragging,
+            self.state.is_focused,
+            self.endless,
             self.mod_range_1,
             self.mod_range_2,
             self.tick_marks,
             self.text_marks,
+            value_text.as_deref(),
+            tooltip.as_deref(),
             &self.style,
         )
     }
@@ -345,6 +983,7 @@ where
         std::any::TypeId::of::<Marker>().hash(state);
 
         self.size.hash(state);
+        self.layout_key.hash(state);
     }
 }
 
@@ -365,8 +1004,12 @@ pub trait Renderer: iced_native::Renderer {
     ///   * the current cursor position
     ///   * the current normal of the [`Knob`]
     ///   * whether the knob is currently being dragged
+    ///   * whether the knob has keyboard focus
+    ///   * whether the knob rotates endlessly through the full circle
     ///   * any tick marks to display
     ///   * any text marks to display
+    ///   * the value text to display in the center, if any
+    ///   * the text of the value tooltip, if it should be displayed
     ///   * the style of the [`Knob`]
     ///
     /// [`Knob`]: struct.Knob.html
@@ -376,10 +1019,14 @@ pub trait Renderer: iced_native::Renderer {
         cursor_position: Point,
         normal: Normal,
         is_dragging: bool,
+        is_focused: bool,
+        endless: bool,
         mod_range_1: Option<&ModulationRange>,
         mod_range_2: Option<&ModulationRange>,
         tick_marks: Option<&tick_marks::Group>,
         text_marks: Option<&text_marks::Group>,
+        value_text: Option<&str>,
+        tooltip: Option<&str>,
         style: &Self::Style,
     ) -> Self::Output;
 }
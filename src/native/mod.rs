@@ -1,14 +1,27 @@
 //! A renderer-agnostic native GUI runtime for Iced Audio.
 
+pub mod accel_curve;
+pub mod drag_axis;
+pub mod drag_mode;
 pub mod h_slider;
 pub mod knob;
 pub mod mod_range_input;
+pub mod over_travel;
 pub mod ramp;
+pub mod reset_default;
+pub mod segment_display;
 pub mod text_marks;
 pub mod tick_marks;
 pub mod v_slider;
+pub mod value_marks;
 pub mod xy_pad;
 
+#[doc(no_inline)]
+pub use accel_curve::AccelCurve;
+#[doc(no_inline)]
+pub use drag_axis::DragAxis;
+#[doc(no_inline)]
+pub use drag_mode::DragMode;
 #[doc(no_inline)]
 pub use h_slider::HSlider;
 #[doc(no_inline)]
@@ -16,8 +29,16 @@ pub use knob::Knob;
 #[doc(no_inline)]
 pub use mod_range_input::ModRangeInput;
 #[doc(no_inline)]
+pub use over_travel::OverTravel;
+#[doc(no_inline)]
 pub use ramp::Ramp;
 #[doc(no_inline)]
+pub use reset_default::ResetDefault;
+#[doc(no_inline)]
+pub use segment_display::SegmentDisplay;
+#[doc(no_inline)]
 pub use v_slider::VSlider;
 #[doc(no_inline)]
+pub use value_marks::ValueMarks;
+#[doc(no_inline)]
 pub use xy_pad::XYPad;
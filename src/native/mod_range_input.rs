@@ -11,7 +11,8 @@ use iced_native::{
 
 use std::hash::Hash;
 
-use crate::core::{Normal, NormalParam};
+use crate::core::{Normal, NormalFloat, NormalParam};
+use crate::native::DragAxis;
 
 static DEFAULT_SIZE: u16 = 10;
 static DEFAULT_SCALAR: f32 = 0.005 / 2.0;
@@ -28,6 +29,8 @@ pub struct ModRangeInput<'a, Message, Renderer: self::Renderer> {
     scalar: f32,
     modifier_scalar: f32,
     modifier_keys: keyboard::ModifiersState,
+    reset_on_right_click: bool,
+    drag_axis: DragAxis,
     style: Renderer::Style,
 }
 
@@ -56,6 +59,8 @@ impl<'a, Message, Renderer: self::Renderer>
                 control: true,
                 ..Default::default()
             },
+            reset_on_right_click: false,
+            drag_axis: DragAxis::default(),
             style: Renderer::Style::default(),
         }
     }
@@ -77,13 +82,14 @@ impl<'a, Message, Renderer: self::Renderer>
         self
     }
 
-    /// Sets how much the [`Normal`] value will change for the [`ModRangeInput`] per `y`
-    /// pixel movement of the mouse.
+    /// Sets how much the [`Normal`] value will change for the [`ModRangeInput`] per
+    /// pixel movement of the mouse along its [`DragAxis`].
     ///
     /// The default value is `0.005`
     ///
     /// [`ModRangeInput`]: struct.ModRangeInput.html
     /// [`Normal`]: ../../core/struct.Normal.html
+    /// [`DragAxis`]: ../drag_axis/enum.DragAxis.html
     pub fn scalar(mut self, scalar: f32) -> Self {
         self.scalar = scalar;
         self
@@ -117,6 +123,34 @@ impl<'a, Message, Renderer: self::Renderer>
         self.modifier_scalar = scalar;
         self
     }
+
+    /// Sets whether a right click on the [`ModRangeInput`] resets its value to
+    /// the default value.
+    ///
+    /// The value is reset when the right mouse button is released without
+    /// the cursor having moved since it was pressed. A right click during a
+    /// drag gesture is ignored.
+    ///
+    /// The default is `false`.
+    ///
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    pub fn reset_on_right_click(mut self, reset: bool) -> Self {
+        self.reset_on_right_click = reset;
+        self
+    }
+
+    /// Sets the [`DragAxis`] of the [`ModRangeInput`], which determines
+    /// whether vertical movement, horizontal movement, or both change the
+    /// value while it is dragged.
+    ///
+    /// The default is `DragAxis::Vertical`.
+    ///
+    /// [`DragAxis`]: ../drag_axis/enum.DragAxis.html
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    pub fn drag_axis(mut self, drag_axis: DragAxis) -> Self {
+        self.drag_axis = drag_axis;
+        self
+    }
 }
 
 /// The local state of an [`ModRangeInput`].
@@ -129,10 +163,12 @@ pub struct State {
     /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
     pub normal_param: NormalParam,
     is_dragging: bool,
+    prev_drag_x: f32,
     prev_drag_y: f32,
-    continuous_normal: f32,
+    continuous_normal: NormalFloat,
     pressed_modifiers: keyboard::ModifiersState,
     last_click: Option<mouse::Click>,
+    right_pressed_at: Option<Point>,
 }
 
 impl State {
@@ -147,12 +183,33 @@ impl State {
         Self {
             normal_param,
             is_dragging: false,
+            prev_drag_x: 0.0,
             prev_drag_y: 0.0,
-            continuous_normal: normal_param.value.as_f32(),
+            continuous_normal: normal_param.value.value(),
             pressed_modifiers: Default::default(),
             last_click: None,
+            right_pressed_at: None,
         }
     }
+
+    /// Returns the current [`Normal`] value of the [`ModRangeInput`].
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    pub fn normal(&self) -> Normal {
+        self.normal_param.value
+    }
+
+    /// Sets the current [`Normal`] value of the [`ModRangeInput`].
+    ///
+    /// This does not send a message.
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    /// [`ModRangeInput`]: struct.ModRangeInput.html
+    pub fn set_normal(&mut self, normal: Normal) {
+        self.normal_param.value = normal;
+        self.continuous_normal = normal.value();
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -193,20 +250,27 @@ where
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::CursorMoved { .. } => {
                     if self.state.is_dragging && cursor_position.y != -1.0 {
-                        let mut movement_y = (cursor_position.y
-                            - self.state.prev_drag_y)
-                            * self.scalar;
+                        let movement_x =
+                            cursor_position.x - self.state.prev_drag_x;
+                        let movement_y =
+                            self.state.prev_drag_y - cursor_position.y;
+
+                        let mut movement = match self.drag_axis {
+                            DragAxis::Vertical => movement_y,
+                            DragAxis::Horizontal => movement_x,
+                            DragAxis::Both => movement_x + movement_y,
+                        } * self.scalar;
 
                         if self
                             .state
                             .pressed_modifiers
                             .matches(self.modifier_keys)
                         {
-                            movement_y *= self.modifier_scalar;
+                            movement *= self.modifier_scalar;
                         }
 
-                        let mut normal =
-                            self.state.continuous_normal - movement_y;
+                        let mut normal = self.state.continuous_normal
+                            + movement as NormalFloat;
 
                         if normal < 0.0 {
                             normal = 0.0;
@@ -215,9 +279,11 @@ where
                         }
 
                         self.state.continuous_normal = normal;
+                        self.state.prev_drag_x = cursor_position.x;
                         self.state.prev_drag_y = cursor_position.y;
 
-                        self.state.normal_param.value = normal.into();
+                        self.state.normal_param.value =
+                            Normal::from_float(normal);
 
                         messages.push((self.on_change)(
                             self.state.normal_param.value,
@@ -234,6 +300,7 @@ where
                         match click.kind() {
                             mouse::click::Kind::Single => {
                                 self.state.is_dragging = true;
+                                self.state.prev_drag_x = cursor_position.x;
                                 self.state.prev_drag_y = cursor_position.y;
                             }
                             _ => {
@@ -251,10 +318,38 @@ where
                         self.state.last_click = Some(click);
                     }
                 }
+                mouse::Event::ButtonPressed(mouse::Button::Right) => {
+                    // A right click during a drag gesture is ignored, so the
+                    // gesture still ends with its release messages.
+                    if self.reset_on_right_click
+                        && !self.state.is_dragging
+                        && layout.bounds().contains(cursor_position)
+                    {
+                        self.state.right_pressed_at = Some(cursor_position);
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Right) => {
+                    if let Some(pressed_at) = self.state.right_pressed_at.take()
+                    {
+                        // Only a click without cursor movement resets.
+                        if pressed_at == cursor_position
+                            && !self.state.is_dragging
+                        {
+                            self.state.normal_param.value =
+                                self.state.normal_param.default;
+                            self.state.continuous_normal =
+                                self.state.normal_param.value.value();
+
+                            messages.push((self.on_change)(
+                                self.state.normal_param.value,
+                            ));
+                        }
+                    }
+                }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
                     self.state.is_dragging = false;
                     self.state.continuous_normal =
-                        self.state.normal_param.value.as_f32();
+                        self.state.normal_param.value.value();
                 }
                 _ => {}
             },
@@ -281,6 +376,7 @@ where
         renderer.draw(
             layout.bounds(),
             cursor_position,
+            self.state.normal_param.value,
             self.state.is_dragging,
             &self.style,
         )
@@ -309,6 +405,7 @@ pub trait Renderer: iced_native::Renderer {
     /// It receives:
     ///   * the bounds of the [`ModRangeInput`]
     ///   * the current cursor position
+    ///   * the current normal of the [`ModRangeInput`]
     ///   * whether the ModRangeInput is currently being dragged
     ///   * the style of the [`ModRangeInput`]
     ///
@@ -317,6 +414,7 @@ pub trait Renderer: iced_native::Renderer {
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
+        normal: Normal,
         is_dragging: bool,
         style: &Self::Style,
     ) -> Self::Output;
@@ -0,0 +1,18 @@
+//! How a slider responds when it is dragged past either end
+
+/// How a slider responds when it is dragged past either end of its range
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OverTravel {
+    /// The movement past the end is accumulated, so the cursor has to travel
+    /// back the same distance before the value starts changing again.
+    Accumulate,
+    /// The movement past the end is discarded, so the value responds as soon
+    /// as the cursor reverses direction.
+    Clamp,
+}
+
+impl Default for OverTravel {
+    fn default() -> Self {
+        OverTravel::Accumulate
+    }
+}
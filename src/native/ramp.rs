@@ -12,7 +12,7 @@ use iced_native::{
 
 use std::hash::Hash;
 
-use crate::core::{Normal, NormalParam};
+use crate::core::{Normal, NormalFloat, NormalParam};
 
 static DEFAULT_WIDTH: u16 = 40;
 static DEFAULT_HEIGHT: u16 = 20;
@@ -34,6 +34,25 @@ impl Default for RampDirection {
     }
 }
 
+/// Which way the line of a [`Ramp`] is curved.
+///
+/// [`Ramp`]: struct.Ramp.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RampCurve {
+    /// The line is curved downward (the [`Normal`] is below `0.5`).
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    Down,
+    /// The line is straight (the [`Normal`] is exactly `0.5`).
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    Flat,
+    /// The line is curved upward (the [`Normal`] is above `0.5`).
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    Up,
+}
+
 /// A ramp GUI widget that controls a [`NormalParam`]. It is usually used to
 /// represent the easing of a parameter between two points in time.
 ///
@@ -46,10 +65,12 @@ pub struct Ramp<'a, Message, Renderer: self::Renderer> {
     scalar: f32,
     modifier_scalar: f32,
     modifier_keys: keyboard::ModifiersState,
+    reset_on_right_click: bool,
     width: Length,
     height: Length,
     style: Renderer::Style,
     direction: RampDirection,
+    zero_detent: Option<f32>,
 }
 
 impl<'a, Message, Renderer: self::Renderer> Ramp<'a, Message, Renderer> {
@@ -82,10 +103,12 @@ impl<'a, Message, Renderer: self::Renderer> Ramp<'a, Message, Renderer> {
                 control: true,
                 ..Default::default()
             },
+            reset_on_right_click: false,
             width: Length::from(Length::Units(DEFAULT_WIDTH)),
             height: Length::from(Length::Units(DEFAULT_HEIGHT)),
             style: Renderer::Style::default(),
             direction,
+            zero_detent: None,
         }
     }
 
@@ -155,6 +178,36 @@ impl<'a, Message, Renderer: self::Renderer> Ramp<'a, Message, Renderer> {
         self.modifier_scalar = scalar;
         self
     }
+
+    /// Sets whether a right click on the [`Ramp`] resets its value to
+    /// the default value.
+    ///
+    /// The value is reset when the right mouse button is released without
+    /// the cursor having moved since it was pressed. A right click during a
+    /// drag gesture is ignored.
+    ///
+    /// The default is `false`.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    pub fn reset_on_right_click(mut self, reset: bool) -> Self {
+        self.reset_on_right_click = reset;
+        self
+    }
+
+    /// Sets the radius of a detent around the center of the [`Ramp`].
+    ///
+    /// While dragging, any value within `radius` of `0.5` (in [`Normal`]
+    /// units) will snap to exactly `0.5`, which makes it easy to get a
+    /// perfectly straight line.
+    ///
+    /// The default is `None` (no detent).
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    /// [`Normal`]: ../../core/struct.Normal.html
+    pub fn zero_detent(mut self, radius: Option<f32>) -> Self {
+        self.zero_detent = radius;
+        self
+    }
 }
 
 /// The local state of a [`Ramp`].
@@ -168,9 +221,10 @@ pub struct State {
     pub normal_param: NormalParam,
     is_dragging: bool,
     prev_drag_y: f32,
-    continuous_normal: f32,
+    continuous_normal: NormalFloat,
     pressed_modifiers: keyboard::ModifiersState,
     last_click: Option<mouse::Click>,
+    right_pressed_at: Option<Point>,
 }
 
 impl State {
@@ -189,11 +243,46 @@ impl State {
             normal_param,
             is_dragging: false,
             prev_drag_y: 0.0,
-            continuous_normal: normal_param.value.as_f32(),
+            continuous_normal: normal_param.value.value(),
             pressed_modifiers: Default::default(),
             last_click: None,
+            right_pressed_at: None,
         }
     }
+
+    /// Returns which way the line of the [`Ramp`] is curved.
+    ///
+    /// [`Ramp`]: struct.Ramp.html
+    pub fn curve(&self) -> RampCurve {
+        let value = self.normal_param.value.as_f32();
+
+        if value < 0.5 {
+            RampCurve::Down
+        } else if value > 0.5 {
+            RampCurve::Up
+        } else {
+            RampCurve::Flat
+        }
+    }
+
+    /// Returns the current [`Normal`] value of the [`Ramp`].
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    /// [`Ramp`]: struct.Ramp.html
+    pub fn normal(&self) -> Normal {
+        self.normal_param.value
+    }
+
+    /// Sets the current [`Normal`] value of the [`Ramp`].
+    ///
+    /// This does not send a message.
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    /// [`Ramp`]: struct.Ramp.html
+    pub fn set_normal(&mut self, normal: Normal) {
+        self.normal_param.value = normal;
+        self.continuous_normal = normal.value();
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -246,8 +335,8 @@ where
                             movement_y *= self.modifier_scalar;
                         }
 
-                        let mut normal =
-                            self.state.continuous_normal - movement_y;
+                        let mut normal = self.state.continuous_normal
+                            - movement_y as NormalFloat;
 
                         if normal < 0.0 {
                             normal = 0.0;
@@ -258,7 +347,14 @@ where
                         self.state.continuous_normal = normal;
                         self.state.prev_drag_y = cursor_position.y;
 
-                        self.state.normal_param.value = normal.into();
+                        if let Some(radius) = self.zero_detent {
+                            if (normal - 0.5).abs() <= radius as NormalFloat {
+                                normal = 0.5;
+                            }
+                        }
+
+                        self.state.normal_param.value =
+                            Normal::from_float(normal);
 
                         messages.push((self.on_change)(
                             self.state.normal_param.value,
@@ -292,10 +388,38 @@ where
                         self.state.last_click = Some(click);
                     }
                 }
+                mouse::Event::ButtonPressed(mouse::Button::Right) => {
+                    // A right click during a drag gesture is ignored, so the
+                    // gesture still ends with its release messages.
+                    if self.reset_on_right_click
+                        && !self.state.is_dragging
+                        && layout.bounds().contains(cursor_position)
+                    {
+                        self.state.right_pressed_at = Some(cursor_position);
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Right) => {
+                    if let Some(pressed_at) = self.state.right_pressed_at.take()
+                    {
+                        // Only a click without cursor movement resets.
+                        if pressed_at == cursor_position
+                            && !self.state.is_dragging
+                        {
+                            self.state.normal_param.value =
+                                self.state.normal_param.default;
+                            self.state.continuous_normal =
+                                self.state.normal_param.value.value();
+
+                            messages.push((self.on_change)(
+                                self.state.normal_param.value,
+                            ));
+                        }
+                    }
+                }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
                     self.state.is_dragging = false;
                     self.state.continuous_normal =
-                        self.state.normal_param.value.as_f32();
+                        self.state.normal_param.value.value();
                 }
                 _ => {}
             },
@@ -0,0 +1,75 @@
+//! Reset the local state of widgets to their default values
+
+use crate::native::{h_slider, knob, mod_range_input, ramp, v_slider, xy_pad};
+
+/// The local state of a widget that can be reset to the default value of
+/// its [`NormalParam`], such as for a "reset patch" button.
+///
+/// Resetting a state does not send a message, so read the new values back
+/// from the states afterwards.
+///
+/// # Example
+///
+/// ```
+/// use iced_audio::native::ResetDefault;
+/// use iced_audio::{h_slider, knob, FloatRange, Normal};
+///
+/// let range = FloatRange::new(-12.0, 12.0);
+///
+/// let mut gain = h_slider::State::new(range.normal_param(6.0, 0.0));
+/// let mut pan = knob::State::new(range.normal_param(-3.0, 0.0));
+///
+/// let states: [&mut dyn ResetDefault; 2] = [&mut gain, &mut pan];
+/// for state in states.iter_mut() {
+///     state.reset_default();
+/// }
+///
+/// assert_eq!(gain.normal(), Normal::center());
+/// assert_eq!(pan.normal(), Normal::center());
+/// ```
+///
+/// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
+pub trait ResetDefault {
+    /// Sets the value of the state to its default value.
+    fn reset_default(&mut self);
+}
+
+impl ResetDefault for h_slider::State {
+    fn reset_default(&mut self) {
+        self.set_normal(self.normal_param.default);
+    }
+}
+
+impl ResetDefault for v_slider::State {
+    fn reset_default(&mut self) {
+        self.set_normal(self.normal_param.default);
+    }
+}
+
+impl ResetDefault for knob::State {
+    fn reset_default(&mut self) {
+        self.set_normal(self.normal_param.default);
+    }
+}
+
+impl ResetDefault for ramp::State {
+    fn reset_default(&mut self) {
+        self.set_normal(self.normal_param.default);
+    }
+}
+
+impl ResetDefault for mod_range_input::State {
+    fn reset_default(&mut self) {
+        self.set_normal(self.normal_param.default);
+    }
+}
+
+impl ResetDefault for xy_pad::State {
+    /// Resets both axes.
+    fn reset_default(&mut self) {
+        self.set_normal(
+            self.normal_param_x.default,
+            self.normal_param_y.default,
+        );
+    }
+}
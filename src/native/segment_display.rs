@@ -0,0 +1,153 @@
+//! Display text such as the formatted value of a parameter as a
+//! seven-segment-style readout
+
+use iced_native::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+static DEFAULT_WIDTH: u16 = 60;
+static DEFAULT_HEIGHT: u16 = 24;
+
+/// A seven-segment-style readout that displays text such as the formatted
+/// value of a parameter.
+///
+/// Digits, `-`, `_`, and the letters `A`-`F`, `H`, `I`, `J`, `K`, `L`, `N`,
+/// `O`, `P`, `R`, `S`, `T`, `U`, `Y` and `Z` are displayed, so the units of
+/// the `format_value` methods of the ranges (such as `"1.0 kHz"` or
+/// `"-6.0 dB"`) can be shown. A `.` lights the decimal point of the preceding
+/// character. A space, or any other character that a seven-segment digit
+/// can't show (such as `%`, `M` or `W`), is displayed as a blank digit.
+///
+/// The digits are right-aligned. If the text doesn't fit inside the bounds
+/// with the style's digit size, the digits are shrunk until it does.
+#[allow(missing_debug_implementations)]
+pub struct SegmentDisplay<Renderer: self::Renderer> {
+    text: String,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<Renderer: self::Renderer> SegmentDisplay<Renderer> {
+    /// Creates a new [`SegmentDisplay`] that displays the given text.
+    ///
+    /// [`SegmentDisplay`]: struct.SegmentDisplay.html
+    pub fn new(text: impl Into<String>) -> Self {
+        SegmentDisplay {
+            text: text.into(),
+            width: Length::from(Length::Units(DEFAULT_WIDTH)),
+            height: Length::from(Length::Units(DEFAULT_HEIGHT)),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the width of the [`SegmentDisplay`].
+    /// The default width is `Length::from(Length::Units(60))`.
+    ///
+    /// [`SegmentDisplay`]: struct.SegmentDisplay.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`SegmentDisplay`].
+    /// The default height is `Length::from(Length::Units(24))`.
+    ///
+    /// [`SegmentDisplay`]: struct.SegmentDisplay.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`SegmentDisplay`].
+    ///
+    /// [`SegmentDisplay`]: struct.SegmentDisplay.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for SegmentDisplay<Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(layout.bounds(), &self.text, &self.style)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`SegmentDisplay`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`SegmentDisplay`] in your user interface.
+///
+/// [`SegmentDisplay`]: struct.SegmentDisplay.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`SegmentDisplay`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`SegmentDisplay`]
+    ///   * the text to display
+    ///   * the style of the [`SegmentDisplay`]
+    ///
+    /// [`SegmentDisplay`]: struct.SegmentDisplay.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        text: &str,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<SegmentDisplay<Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        segment_display: SegmentDisplay<Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(segment_display)
+    }
+}
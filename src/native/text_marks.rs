@@ -2,7 +2,7 @@
 
 use std::fmt::Debug;
 
-use crate::core::Normal;
+use crate::core::{FreqRange, IntRange, Normal, NoteDivisionRange};
 
 /// A group of text marks.
 #[derive(Debug, Clone)]
@@ -130,6 +130,87 @@ impl Group {
 
         vec.into()
     }
+
+    /// Creates a group of text marks labeling each decade between
+    /// `start_hz` and `end_hz` (inclusive) of a [`FreqRange`], such as
+    /// `"100"`, `"1k"`, and `"10k"`
+    ///
+    /// The labels line up with the tier 1 tick marks created by
+    /// [`tick_marks::Group::decades`]. Frequencies outside of the range are
+    /// skipped.
+    ///
+    /// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+    /// [`tick_marks::Group::decades`]: ../tick_marks/struct.Group.html#method.decades
+    pub fn decades(range: &FreqRange, start_hz: f32, end_hz: f32) -> Self {
+        let mut vec: Vec<(Normal, String)> = Vec::new();
+
+        for (freq, is_decade) in range.decade_frequencies(start_hz, end_hz) {
+            if is_decade {
+                let text = if freq >= 1000.0 {
+                    format!("{}k", freq / 1000.0)
+                } else {
+                    format!("{}", freq)
+                };
+
+                vec.push((range.map_to_normal(freq), text));
+            }
+        }
+
+        vec.into()
+    }
+
+    /// Creates a group of text marks with the label of each note division
+    /// placed at its position in a [`NoteDivisionRange`]
+    ///
+    /// [`NoteDivisionRange`]: ../../core/range/struct.NoteDivisionRange.html
+    pub fn note_divisions(range: &NoteDivisionRange) -> Self {
+        let mut vec: Vec<(Normal, String)> =
+            Vec::with_capacity(range.divisions().len());
+
+        for (index, division) in range.divisions().iter().enumerate() {
+            vec.push((range.index_to_normal(index), division.label()));
+        }
+
+        vec.into()
+    }
+}
+
+impl IntRange {
+    /// Creates a [`text_marks::Group`] with each label placed at the
+    /// position of its step in an `IntRange` created with [`enumerated`]
+    ///
+    /// [`text_marks::Group`]: ../../native/text_marks/struct.Group.html
+    /// [`enumerated`]: #method.enumerated
+    pub fn enumerated_text_marks(labels: &[&str]) -> Group {
+        Group::evenly_spaced(labels)
+    }
+}
+
+impl FreqRange {
+    /// Creates a group of text marks labeling each decade between
+    /// `start_hz` and `end_hz` (inclusive), such as `"100"`, `"1k"`, and
+    /// `"10k"`
+    ///
+    /// This is the same as [`text_marks::Group::decades`], and lines up
+    /// with the tier 1 tick marks created by [`octave_tick_marks`].
+    ///
+    /// [`text_marks::Group::decades`]: ../../native/text_marks/struct.Group.html#method.decades
+    /// [`octave_tick_marks`]: #method.octave_tick_marks
+    pub fn octave_text_marks(&self, start_hz: f32, end_hz: f32) -> Group {
+        Group::decades(self, start_hz, end_hz)
+    }
+}
+
+impl NoteDivisionRange {
+    /// Creates a group of text marks with the label of each note division
+    /// placed at its position in this range
+    ///
+    /// This is the same as [`text_marks::Group::note_divisions`].
+    ///
+    /// [`text_marks::Group::note_divisions`]: ../../native/text_marks/struct.Group.html#method.note_divisions
+    pub fn text_marks(&self) -> Group {
+        Group::note_divisions(self)
+    }
 }
 
 impl From<&[(Normal, &str)]> for Group {
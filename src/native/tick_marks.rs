@@ -2,7 +2,7 @@
 
 use std::fmt::Debug;
 
-use crate::core::Normal;
+use crate::core::{FreqRange, Normal};
 
 /// A group of tick marks.
 ///
@@ -184,6 +184,29 @@ impl Group {
         Self::from_normalized(&tick_marks)
     }
 
+    /// Creates a [`Group`] of tick marks at the decades of a [`FreqRange`]
+    /// and their `2` and `5` subdivisions, between `start_hz` and `end_hz`
+    /// (inclusive)
+    ///
+    /// A tier 1 tick mark is placed at each decade (`100 Hz`, `1 kHz`,
+    /// `10 kHz`, ...), and tier 2 tick marks are placed at the `2` and `5`
+    /// subdivisions of each decade (`200 Hz`, `500 Hz`, `2 kHz`, ...).
+    /// Frequencies outside of the range are skipped.
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`FreqRange`]: ../../core/range/struct.FreqRange.html
+    pub fn decades(range: &FreqRange, start_hz: f32, end_hz: f32) -> Self {
+        let mut tick_marks: Vec<(Normal, Tier)> = Vec::new();
+
+        for (freq, is_decade) in range.decade_frequencies(start_hz, end_hz) {
+            let tier = if is_decade { Tier::One } else { Tier::Two };
+
+            tick_marks.push((range.map_to_normal(freq), tier));
+        }
+
+        Self::from_normalized(&tick_marks)
+    }
+
     /// Returns the positions of the tier 1 tick marks.
     /// Returns `None` if there are no tier 1 tick marks.
     pub fn tier_1(&self) -> Option<&Vec<Normal>> {
@@ -220,6 +243,20 @@ impl Group {
     }
 }
 
+impl FreqRange {
+    /// Creates a group of tick marks for a logarithmic frequency layout
+    /// between `start_hz` and `end_hz` (inclusive)
+    ///
+    /// This is the same as [`tick_marks::Group::decades`]: a tier 1 tick
+    /// mark is placed at each decade, and tier 2 tick marks at the `2` and
+    /// `5` subdivisions of each decade.
+    ///
+    /// [`tick_marks::Group::decades`]: ../../native/tick_marks/struct.Group.html#method.decades
+    pub fn octave_tick_marks(&self, start_hz: f32, end_hz: f32) -> Group {
+        Group::decades(self, start_hz, end_hz)
+    }
+}
+
 impl From<Vec<(Normal, Tier)>> for Group {
     fn from(vec: Vec<(Normal, Tier)>) -> Self {
         Self::from_normalized(&vec)
@@ -0,0 +1,246 @@
+//! Display tick marks and text marks on their own, such as shared marks
+//! placed between two stacked sliders
+
+use iced_native::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+use crate::native::{text_marks, tick_marks};
+
+static DEFAULT_THICKNESS: u16 = 30;
+
+/// The axis a [`ValueMarks`] widget places its marks along
+///
+/// [`ValueMarks`]: struct.ValueMarks.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Orientation {
+    /// Along the horizontal axis, like the marks of an `HSlider`
+    Horizontal,
+    /// Along the vertical axis, like the marks of a `VSlider`
+    Vertical,
+}
+
+impl Default for Orientation {
+    fn default() -> Self {
+        Orientation::Horizontal
+    }
+}
+
+/// A widget that draws a group of tick marks and/or text marks into its
+/// bounds, decoupled from any slider.
+///
+/// This is useful for placing one set of marks between two stacked sliders
+/// instead of drawing them on each slider.
+#[allow(missing_debug_implementations)]
+pub struct ValueMarks<'a, Renderer: self::Renderer> {
+    tick_marks: Option<&'a tick_marks::Group>,
+    text_marks: Option<&'a text_marks::Group>,
+    orientation: Orientation,
+    padding: u16,
+    width: Length,
+    height: Length,
+    style: Renderer::Style,
+}
+
+impl<'a, Renderer: self::Renderer> ValueMarks<'a, Renderer> {
+    /// Creates a new [`ValueMarks`] that places its marks along the given
+    /// [`Orientation`].
+    ///
+    /// [`ValueMarks`]: struct.ValueMarks.html
+    /// [`Orientation`]: enum.Orientation.html
+    pub fn new(orientation: Orientation) -> Self {
+        let (width, height) = match orientation {
+            Orientation::Horizontal => {
+                (Length::Fill, Length::from(Length::Units(DEFAULT_THICKNESS)))
+            }
+            Orientation::Vertical => {
+                (Length::from(Length::Units(DEFAULT_THICKNESS)), Length::Fill)
+            }
+        };
+
+        ValueMarks {
+            tick_marks: None,
+            text_marks: None,
+            orientation,
+            padding: 0,
+            width,
+            height,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the tick marks to display. Note your [`StyleSheet`] must
+    /// also implement `tick_marks_style(&self) -> Option<TickMarksStyle>` for
+    /// them to display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/value_marks/trait.StyleSheet.html
+    pub fn tick_marks(mut self, tick_marks: &'a tick_marks::Group) -> Self {
+        self.tick_marks = Some(tick_marks);
+        self
+    }
+
+    /// Sets the text marks to display. Note your [`StyleSheet`] must
+    /// also implement `text_marks_style(&self) -> Option<TextMarksStyle>` for
+    /// them to display (which the default style does).
+    ///
+    /// [`StyleSheet`]: ../../style/value_marks/trait.StyleSheet.html
+    pub fn text_marks(mut self, text_marks: &'a text_marks::Group) -> Self {
+        self.text_marks = Some(text_marks);
+        self
+    }
+
+    /// Sets the padding in pixels between each end of the [`ValueMarks`]
+    /// and the first and last mark along its axis.
+    ///
+    /// To line the marks up with a slider, set this to half the size of
+    /// the handle of the slider (plus the width of its border, if any),
+    /// plus the end padding of the slider.
+    ///
+    /// The default padding is `0`.
+    ///
+    /// [`ValueMarks`]: struct.ValueMarks.html
+    pub fn padding(mut self, padding: u16) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the width of the [`ValueMarks`].
+    /// The default width is `Length::Fill` for a horizontal [`ValueMarks`],
+    /// and `Length::from(Length::Units(30))` for a vertical one.
+    ///
+    /// [`ValueMarks`]: struct.ValueMarks.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`ValueMarks`].
+    /// The default height is `Length::from(Length::Units(30))` for a
+    /// horizontal [`ValueMarks`], and `Length::Fill` for a vertical one.
+    ///
+    /// [`ValueMarks`]: struct.ValueMarks.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`ValueMarks`].
+    ///
+    /// [`ValueMarks`]: struct.ValueMarks.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ValueMarks<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let padding = f32::from(self.padding);
+
+        let mark_bounds = match self.orientation {
+            Orientation::Horizontal => Rectangle {
+                x: bounds.x + padding,
+                width: (bounds.width - (padding * 2.0)).max(0.0),
+                ..bounds
+            },
+            Orientation::Vertical => Rectangle {
+                y: bounds.y + padding,
+                height: (bounds.height - (padding * 2.0)).max(0.0),
+                ..bounds
+            },
+        };
+
+        renderer.draw(
+            mark_bounds,
+            self.orientation,
+            self.tick_marks,
+            self.text_marks,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`ValueMarks`].
+///
+/// Your renderer will need to implement this trait before being
+/// able to use a [`ValueMarks`] in your user interface.
+///
+/// [`ValueMarks`]: struct.ValueMarks.html
+pub trait Renderer: iced_native::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`ValueMarks`].
+    ///
+    /// It receives:
+    ///   * the bounds to place the marks in, with the padding removed
+    ///   * the [`Orientation`] of the [`ValueMarks`]
+    ///   * any tick marks to display
+    ///   * any text marks to display
+    ///   * the style of the [`ValueMarks`]
+    ///
+    /// [`ValueMarks`]: struct.ValueMarks.html
+    /// [`Orientation`]: enum.Orientation.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        orientation: Orientation,
+        tick_marks: Option<&tick_marks::Group>,
+        text_marks: Option<&text_marks::Group>,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<ValueMarks<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        value_marks: ValueMarks<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(value_marks)
+    }
+}
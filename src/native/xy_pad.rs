@@ -12,7 +12,8 @@ use iced_native::{
 
 use std::hash::Hash;
 
-use crate::core::{Normal, NormalParam};
+use crate::core::{Normal, NormalFloat, NormalParam};
+use crate::native::AccelCurve;
 
 static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
 
@@ -28,8 +29,16 @@ static DEFAULT_MODIFIER_SCALAR: f32 = 0.02;
 pub struct XYPad<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
     on_change: Box<dyn Fn(Normal, Normal) -> Message>,
+    on_grab: Option<Box<dyn Fn() -> Message>>,
+    on_release: Option<Box<dyn Fn() -> Message>>,
+    accessibility_label: Option<String>,
+    value_description: Option<Box<dyn Fn(Normal, Normal) -> String>>,
     modifier_scalar: f32,
+    fine_mode_accel: Option<AccelCurve>,
     modifier_keys: keyboard::ModifiersState,
+    reset_on_right_click: bool,
+    jump_on_background_click: bool,
+    handle_size: u16,
     size: Length,
     style: Renderer::Style,
 }
@@ -50,16 +59,54 @@ impl<'a, Message, Renderer: self::Renderer> XYPad<'a, Message, Renderer> {
         XYPad {
             state,
             on_change: Box::new(on_change),
+            on_grab: None,
+            on_release: None,
+            accessibility_label: None,
+            value_description: None,
             modifier_scalar: DEFAULT_MODIFIER_SCALAR,
+            fine_mode_accel: None,
             modifier_keys: keyboard::ModifiersState {
                 control: true,
                 ..Default::default()
             },
+            reset_on_right_click: false,
+            jump_on_background_click: true,
+            handle_size: 0,
             size: Length::Fill,
             style: Renderer::Style::default(),
         }
     }
 
+    /// Sets a function that will be called when the user grabs the
+    /// [`XYPad`] to begin a drag gesture.
+    ///
+    /// This message is sent independently of the value change messages,
+    /// so it can be used to begin a gesture such as writing host automation.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn on_grab<F>(mut self, on_grab: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_grab = Some(Box::new(on_grab));
+        self
+    }
+
+    /// Sets a function that will be called when the user releases
+    /// an [`XYPad`] that was being dragged.
+    ///
+    /// This message is sent independently of the value change messages,
+    /// so it can be used to end a gesture such as writing host automation.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn on_release<F>(mut self, on_release: F) -> Self
+    where
+        F: 'static + Fn() -> Message,
+    {
+        self.on_release = Some(Box::new(on_release));
+        self
+    }
+
     /// Sets the size of the [`XYPad`].
     ///
     /// [`XYPad`]: struct.XYPad.html
@@ -102,6 +149,132 @@ impl<'a, Message, Renderer: self::Renderer> XYPad<'a, Message, Renderer> {
         self.modifier_scalar = scalar;
         self
     }
+
+    /// Sets an [`AccelCurve`] that increases the modifier scalar during a
+    /// drag gesture, either with the distance dragged while holding the
+    /// modifier keys or with the speed of the cursor.
+    ///
+    /// The default is `None`, which uses the constant modifier scalar.
+    ///
+    /// [`AccelCurve`]: ../accel_curve/enum.AccelCurve.html
+    pub fn fine_mode_accel(mut self, accel: Option<AccelCurve>) -> Self {
+        self.fine_mode_accel = accel;
+        self
+    }
+
+    /// Sets whether a right click on the [`XYPad`] resets its value to
+    /// the default value.
+    ///
+    /// The value is reset when the right mouse button is released without
+    /// the cursor having moved since it was pressed. A right click during a
+    /// drag gesture is ignored.
+    ///
+    /// The default is `false`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn reset_on_right_click(mut self, reset: bool) -> Self {
+        self.reset_on_right_click = reset;
+        self
+    }
+
+    /// Sets a label that describes what the [`XYPad`] controls, such as
+    /// `"Cutoff"`, for assistive technology like screen readers.
+    ///
+    /// The label is not drawn. It is stored so it can be read back with
+    /// [`accessible_label`] to build an accessibility tree.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`accessible_label`]: struct.XYPad.html#method.accessible_label
+    pub fn accessibility_label(mut self, label: impl Into<String>) -> Self {
+        self.accessibility_label = Some(label.into());
+        self
+    }
+
+    /// Sets a function that describes the values of the [`XYPad`] in words
+    /// for assistive technology like screen readers, such as `"440 hertz"`.
+    ///
+    /// The description is not drawn. It can be read back with
+    /// [`accessible_value`] to build an accessibility tree.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`accessible_value`]: struct.XYPad.html#method.accessible_value
+    pub fn value_description<F>(mut self, value_description: F) -> Self
+    where
+        F: 'static + Fn(Normal, Normal) -> String,
+    {
+        self.value_description = Some(Box::new(value_description));
+        self
+    }
+
+    /// Returns the label set with [`accessibility_label`], if any.
+    ///
+    /// [`accessibility_label`]: struct.XYPad.html#method.accessibility_label
+    pub fn accessible_label(&self) -> Option<&str> {
+        self.accessibility_label.as_deref()
+    }
+
+    /// Returns the description of the current value of the [`XYPad`] from the
+    /// function set with [`value_description`], if any.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`value_description`]: struct.XYPad.html#method.value_description
+    pub fn accessible_value(&self) -> Option<String> {
+        self.value_description.as_ref().map(|value_description| {
+            value_description(
+                self.state.normal_param_x.value,
+                self.state.normal_param_y.value,
+            )
+        })
+    }
+
+    /// Sets whether clicking the background of the [`XYPad`] makes the
+    /// handle jump to the cursor.
+    ///
+    /// When disabled, a click anywhere on the [`XYPad`] begins a relative
+    /// drag from the current position of the handle. Clicks on the handle
+    /// itself never make it jump (see [`handle_size`]).
+    ///
+    /// The default is `true`.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`handle_size`]: struct.XYPad.html#method.handle_size
+    pub fn jump_on_background_click(mut self, jump: bool) -> Self {
+        self.jump_on_background_click = jump;
+        self
+    }
+
+    /// Sets the diameter in pixels of the handle as it is drawn by the style
+    /// of the [`XYPad`].
+    ///
+    /// A click within this diameter of the center of the handle begins a
+    /// relative drag that keeps the offset between the cursor and the
+    /// handle, instead of making the handle jump to the cursor.
+    ///
+    /// The default size is `0`, which makes every click jump if
+    /// [`jump_on_background_click`] is enabled.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    /// [`jump_on_background_click`]: struct.XYPad.html#method.jump_on_background_click
+    pub fn handle_size(mut self, size: u16) -> Self {
+        self.handle_size = size;
+        self
+    }
+
+    /// Returns the modifier scalar for a cursor event that moved the cursor
+    /// by `pixels`, accelerated by the fine mode [`AccelCurve`] if one is set.
+    ///
+    /// [`AccelCurve`]: ../accel_curve/enum.AccelCurve.html
+    fn fine_scalar(&mut self, pixels: f32) -> f32 {
+        self.state.fine_drag_distance += pixels.abs();
+
+        match self.fine_mode_accel {
+            Some(accel) => {
+                self.modifier_scalar
+                    * accel.factor(self.state.fine_drag_distance, pixels)
+            }
+            None => self.modifier_scalar,
+        }
+    }
 }
 
 /// The local state of a [`XYPad`].
@@ -118,12 +291,15 @@ pub struct State {
     /// [`NormalParam`]: ../../core/normal_param/struct.NormalParam.html
     pub normal_param_y: NormalParam,
     is_dragging: bool,
+    drag_anchor: Point,
     prev_drag_x: f32,
     prev_drag_y: f32,
-    continuous_normal_x: f32,
-    continuous_normal_y: f32,
+    continuous_normal_x: NormalFloat,
+    continuous_normal_y: NormalFloat,
     pressed_modifiers: keyboard::ModifiersState,
+    fine_drag_distance: f32,
     last_click: Option<mouse::Click>,
+    right_pressed_at: Option<Point>,
 }
 
 impl State {
@@ -143,14 +319,53 @@ impl State {
             normal_param_x,
             normal_param_y,
             is_dragging: false,
+            drag_anchor: Point::ORIGIN,
             prev_drag_x: 0.0,
             prev_drag_y: 0.0,
-            continuous_normal_x: normal_param_x.value.as_f32(),
-            continuous_normal_y: normal_param_y.value.as_f32(),
+            continuous_normal_x: normal_param_x.value.value(),
+            continuous_normal_y: normal_param_y.value.value(),
             pressed_modifiers: Default::default(),
+            fine_drag_distance: 0.0,
             last_click: None,
+            right_pressed_at: None,
         }
     }
+
+    /// Returns the position of the cursor where the current drag gesture
+    /// started, or `None` if the [`XYPad`] is not being dragged.
+    ///
+    /// This can be used to draw a custom cursor that stays anchored to the
+    /// point where the [`XYPad`] was grabbed.
+    ///
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn drag_anchor(&self) -> Option<Point> {
+        if self.is_dragging {
+            Some(self.drag_anchor)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the current `x` and `y` [`Normal`] values of the [`XYPad`].
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn normal(&self) -> (Normal, Normal) {
+        (self.normal_param_x.value, self.normal_param_y.value)
+    }
+
+    /// Sets the current `x` and `y` [`Normal`] values of the [`XYPad`].
+    ///
+    /// This does not send a message.
+    ///
+    /// [`Normal`]: ../../core/struct.Normal.html
+    /// [`XYPad`]: struct.XYPad.html
+    pub fn set_normal(&mut self, normal_x: Normal, normal_y: Normal) {
+        self.normal_param_x.value = normal_x;
+        self.normal_param_y.value = normal_y;
+        self.continuous_normal_x = normal_x.value();
+        self.continuous_normal_y = normal_y.value();
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -218,23 +433,34 @@ where
                                 .pressed_modifiers
                                 .matches(self.modifier_keys)
                             {
-                                movement_x *= self.modifier_scalar;
-                                movement_y *= self.modifier_scalar;
+                                let fine_scalar = self.fine_scalar(
+                                    (cursor_position.x
+                                        - self.state.prev_drag_x)
+                                        .hypot(
+                                            cursor_position.y
+                                                - self.state.prev_drag_y,
+                                        ),
+                                );
+
+                                movement_x *= fine_scalar;
+                                movement_y *= fine_scalar;
                             }
 
-                            let normal_x =
-                                self.state.continuous_normal_x + movement_x;
-                            let normal_y =
-                                self.state.continuous_normal_y - movement_y;
+                            let normal_x = self.state.continuous_normal_x
+                                + movement_x as NormalFloat;
+                            let normal_y = self.state.continuous_normal_y
+                                - movement_y as NormalFloat;
 
                             self.state.prev_drag_x = cursor_position.x;
                             self.state.prev_drag_y = cursor_position.y;
 
                             self.state.continuous_normal_x = normal_x;
-                            self.state.normal_param_x.value = normal_x.into();
+                            self.state.normal_param_x.value =
+                                Normal::from_float(normal_x);
 
                             self.state.continuous_normal_y = normal_y;
-                            self.state.normal_param_y.value = normal_y.into();
+                            self.state.normal_param_y.value =
+                                Normal::from_float(normal_y);
 
                             messages.push((self.on_change)(
                                 self.state.normal_param_x.value,
@@ -253,9 +479,15 @@ where
                         match click.kind() {
                             mouse::click::Kind::Single => {
                                 self.state.is_dragging = true;
+                                self.state.fine_drag_distance = 0.0;
+                                self.state.drag_anchor = cursor_position;
                                 self.state.prev_drag_x = cursor_position.x;
                                 self.state.prev_drag_y = cursor_position.y;
 
+                                if let Some(on_grab) = &self.on_grab {
+                                    messages.push((on_grab)());
+                                }
+
                                 let bounds_size = {
                                     if layout.bounds().width
                                         <= layout.bounds().height
@@ -266,26 +498,49 @@ where
                                     }
                                 };
 
-                                let normal_x = (cursor_position.x
-                                    - layout.bounds().x)
-                                    / bounds_size;
-
-                                let normal_y = 1.0
-                                    - ((cursor_position.y - layout.bounds().y)
-                                        / bounds_size);
-
-                                self.state.continuous_normal_x = normal_x;
-                                self.state.normal_param_x.value =
-                                    normal_x.into();
-
-                                self.state.continuous_normal_y = normal_y;
-                                self.state.normal_param_y.value =
-                                    normal_y.into();
-
-                                messages.push((self.on_change)(
-                                    self.state.normal_param_x.value,
-                                    self.state.normal_param_y.value,
-                                ));
+                                let handle_x = layout.bounds().x
+                                    + self
+                                        .state
+                                        .normal_param_x
+                                        .value
+                                        .scale(bounds_size);
+                                let handle_y = layout.bounds().y
+                                    + self
+                                        .state
+                                        .normal_param_y
+                                        .value
+                                        .scale_inv(bounds_size);
+
+                                let on_handle = self.handle_size != 0
+                                    && (cursor_position.x - handle_x)
+                                        .hypot(cursor_position.y - handle_y)
+                                        <= f32::from(self.handle_size) / 2.0;
+
+                                if self.jump_on_background_click && !on_handle {
+                                    let normal_x = (cursor_position.x
+                                        - layout.bounds().x)
+                                        / bounds_size;
+
+                                    let normal_y = 1.0
+                                        - ((cursor_position.y
+                                            - layout.bounds().y)
+                                            / bounds_size);
+
+                                    self.state.continuous_normal_x =
+                                        normal_x as NormalFloat;
+                                    self.state.normal_param_x.value =
+                                        normal_x.into();
+
+                                    self.state.continuous_normal_y =
+                                        normal_y as NormalFloat;
+                                    self.state.normal_param_y.value =
+                                        normal_y.into();
+
+                                    messages.push((self.on_change)(
+                                        self.state.normal_param_x.value,
+                                        self.state.normal_param_y.value,
+                                    ));
+                                }
                             }
                             _ => {
                                 self.state.is_dragging = false;
@@ -305,12 +560,51 @@ where
                         self.state.last_click = Some(click);
                     }
                 }
+                mouse::Event::ButtonPressed(mouse::Button::Right) => {
+                    // A right click during a drag gesture is ignored, so the
+                    // gesture still ends with its release messages.
+                    if self.reset_on_right_click
+                        && !self.state.is_dragging
+                        && layout.bounds().contains(cursor_position)
+                    {
+                        self.state.right_pressed_at = Some(cursor_position);
+                    }
+                }
+                mouse::Event::ButtonReleased(mouse::Button::Right) => {
+                    if let Some(pressed_at) = self.state.right_pressed_at.take()
+                    {
+                        // Only a click without cursor movement resets.
+                        if pressed_at == cursor_position
+                            && !self.state.is_dragging
+                        {
+                            self.state.normal_param_x.value =
+                                self.state.normal_param_x.default;
+                            self.state.normal_param_y.value =
+                                self.state.normal_param_y.default;
+                            self.state.continuous_normal_x =
+                                self.state.normal_param_x.value.value();
+                            self.state.continuous_normal_y =
+                                self.state.normal_param_y.value.value();
+
+                            messages.push((self.on_change)(
+                                self.state.normal_param_x.value,
+                                self.state.normal_param_y.value,
+                            ));
+                        }
+                    }
+                }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
+                    if self.state.is_dragging {
+                        if let Some(on_release) = &self.on_release {
+                            messages.push((on_release)());
+                        }
+                    }
+
                     self.state.is_dragging = false;
                     self.state.continuous_normal_x =
-                        self.state.normal_param_x.value.as_f32();
+                        self.state.normal_param_x.value.value();
                     self.state.continuous_normal_y =
-                        self.state.normal_param_y.value.as_f32();
+                        self.state.normal_param_y.value.value();
                 }
                 _ => {}
             },
@@ -65,6 +65,17 @@ pub const XY_PAD_CENTER_LINE: Color = Color {
     a: 0.5,
 };
 
+pub const FOCUS: Color = Color::from_rgb(0.3, 0.55, 0.9);
+
+pub const SEGMENT_DISPLAY_BACK: Color = Color::from_rgb(0.12, 0.12, 0.12);
+pub const SEGMENT_DISPLAY_LIT: Color = Color::from_rgb(1.0, 0.27, 0.16);
+pub const SEGMENT_DISPLAY_UNLIT: Color = Color {
+    r: 1.0,
+    g: 0.27,
+    b: 0.16,
+    a: 0.1,
+};
+
 /*
 pub const DB_METER_BACK: Color = Color::from_rgb(0.45, 0.45, 0.45);
 pub const DB_METER_BORDER: Color = Color::from_rgb(0.2, 0.2, 0.2);
@@ -0,0 +1,43 @@
+//! Style for the keyboard focus outline of a widget
+use iced_native::Color;
+
+use crate::style::{default_colors, scale_u16};
+
+/// The style of the outline drawn around a widget that has keyboard focus.
+#[derive(Debug, Copy, Clone)]
+pub struct Style {
+    /// The width of the outline.
+    pub border_width: u16,
+    /// The radius of the outline.
+    pub border_radius: u16,
+    /// The color of the outline.
+    pub border_color: Color,
+    /// The spacing in pixels between the bounds of the widget and the outline.
+    pub offset: u16,
+}
+
+impl Style {
+    /// Returns the [`Style`] with all of its sizes multiplied by `scale`,
+    /// such as for HiDPI displays. The color is unchanged.
+    ///
+    /// [`Style`]: struct.Style.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        Self {
+            border_width: scale_u16(self.border_width, scale),
+            border_radius: scale_u16(self.border_radius, scale),
+            offset: scale_u16(self.offset, scale),
+            ..*self
+        }
+    }
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            border_width: 1,
+            border_radius: 2,
+            border_color: default_colors::FOCUS,
+            offset: 2,
+        }
+    }
+}
@@ -2,12 +2,11 @@
 //!
 //! [`Knob`]: ../native/knob/struct.Knob.html
 
-use iced_native::Color;
-//use iced_native::image;
+use iced_native::{image, Color, Font, Rectangle};
 
 pub use iced_graphics::canvas::LineCap;
 
-use crate::style::{default_colors, text_marks, tick_marks};
+use crate::style::{default_colors, focus, text_marks, tick_marks, tooltip};
 use crate::KnobAngleRange;
 
 /// The appearance of a [`Knob`],
@@ -98,6 +97,20 @@ pub struct LineNotch {
     pub offset: StyleLength,
 }
 
+/// Texture notch
+///
+/// [`Handle`]: https://docs.rs/iced/0.1.1/iced/widget/image/struct.Handle.html
+#[derive(Debug, Clone)]
+pub struct TextureNotch {
+    /// The [`Handle`] to the image texture
+    pub image_handle: image::Handle,
+    /// The bounds of the image texture, where the origin is in the
+    /// center of the notch.
+    pub image_bounds: Rectangle,
+    /// The offset from the edge of the knob to the center of the notch.
+    pub offset: StyleLength,
+}
+
 /// The shape of the notch
 #[derive(Debug, Clone)]
 pub enum NotchShape {
@@ -107,6 +120,9 @@ pub enum NotchShape {
     Circle(CircleNotch),
     /// Line notch
     Line(LineNotch),
+    /// Texture notch. The texture is placed at the current value angle
+    /// but is not rotated.
+    Texture(TextureNotch),
 }
 
 /// A classic circular [`Style`] of a [`Knob`]
@@ -123,6 +139,10 @@ pub struct CircleStyle {
     pub border_color: Color,
     /// The shape of the notch
     pub notch: NotchShape,
+    /// The `(width, color)` of each ring in a bezel around the knob, from
+    /// the outside in. The knob and its notch are drawn inside the innermost
+    /// ring. Set this to an empty `Vec` for no bezel.
+    pub rings: Vec<(u16, Color)>,
 }
 
 /// A modern arc [`Style`] of a [`Knob`]
@@ -168,6 +188,25 @@ pub struct ArcBipolarStyle {
     pub cap: LineCap,
 }
 
+/// Where the filled portion of a value arc grows from
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ArcFillMode {
+    /// Fill from the start of the arc towards the value.
+    FromStart,
+    /// Fill outward from the center of the arc in both directions, which
+    /// is the top of the knob for a symmetric [`KnobAngleRange`]. This is
+    /// useful for center-zero bipolar knobs.
+    ///
+    /// [`KnobAngleRange`]: ../../core/knob_angle_range/struct.KnobAngleRange.html
+    FromCenter,
+}
+
+impl std::default::Default for ArcFillMode {
+    fn default() -> Self {
+        ArcFillMode::FromStart
+    }
+}
+
 /// A style for a value arc around a [`Knob`]
 ///
 /// [`Knob`]: ../../native/knob/struct.Knob.html
@@ -180,12 +219,17 @@ pub struct ValueArcStyle {
     /// The color of the empty background portion in the arc. Set this to
     /// `None` for no background arc.
     pub empty_color: Option<Color>,
-    /// The color of a filled portion of the ring. If `right_filled_color` is
-    /// `Some`, then this will only apply to the left side of the ring.
+    /// The color of a filled portion of the ring. With
+    /// `ArcFillMode::FromCenter` and a `right_filled_color`, this only
+    /// applies to the left side of the ring.
     pub left_filled_color: Color,
-    /// The color of a filled portion on the right side of the ring.
-    /// Set this to `None` for unipolar mode.
+    /// The color of a filled portion on the right side of the ring with
+    /// `ArcFillMode::FromCenter`. Set this to `None` to use
+    /// `left_filled_color` on both sides. This is unused with
+    /// `ArcFillMode::FromStart`.
     pub right_filled_color: Option<Color>,
+    /// Where the filled portion of the ring grows from
+    pub fill_mode: ArcFillMode,
     /// The cap at the ends of the arc
     pub cap: LineCap,
 }
@@ -255,6 +299,40 @@ impl std::default::Default for TextMarksStyle {
     }
 }
 
+/// Style of the value text displayed in the center of a [`Knob`].
+///
+/// [`Knob`]: ../../native/knob/struct.Knob.html
+#[derive(Debug, Copy, Clone)]
+pub struct CenterTextStyle {
+    /// The color of the text
+    pub color: Color,
+    /// The font of the text
+    pub font: Font,
+    /// The size of the text
+    pub text_size: u16,
+    /// The smallest size the text will shrink to when it is too wide to fit
+    /// inside the knob. Any text that still doesn't fit is clipped to the
+    /// bounds of the knob.
+    pub min_text_size: u16,
+    /// The approximate width of a character relative to the size of the text.
+    /// This is used to shrink text that would overflow the knob.
+    ///
+    /// The default is `0.6`.
+    pub char_width: f32,
+}
+
+impl std::default::Default for CenterTextStyle {
+    fn default() -> Self {
+        Self {
+            color: default_colors::TEXT_MARK,
+            font: Font::Default,
+            text_size: 12,
+            min_text_size: 7,
+            char_width: 0.6,
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a [`Knob`].
 ///
 /// [`Knob`]: ../../native/knob/struct.Knob.html
@@ -330,6 +408,34 @@ pub trait StyleSheet {
     fn text_marks_style(&self) -> Option<TextMarksStyle> {
         None
     }
+
+    /// The style of the value text displayed in the center of a [`Knob`]
+    ///
+    /// For no value text, don't override this or set this to return `None`.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn center_text_style(&self) -> Option<CenterTextStyle> {
+        None
+    }
+
+    /// The style of the value tooltip for a [`Knob`]
+    ///
+    /// For no tooltip, don't override this or set this to return `None`.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn tooltip_style(&self) -> Option<tooltip::Style> {
+        None
+    }
+
+    /// The style of the outline drawn around a [`Knob`] that has keyboard
+    /// focus
+    ///
+    /// For no focus outline, don't override this or set this to return `None`.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    fn focus_style(&self) -> Option<focus::Style> {
+        None
+    }
 }
 
 struct Default;
@@ -345,6 +451,7 @@ impl Default {
             diameter: StyleLength::Scaled(0.17),
             offset: StyleLength::Scaled(0.15),
         }),
+        rings: Vec::new(),
     };
 }
 impl StyleSheet for Default {
@@ -379,6 +486,8 @@ impl StyleSheet for Default {
                     diameter: 2,
                     color: default_colors::TICK_TIER_3,
                 },
+                tier_offsets: tick_marks::TierOffsets::default(),
+                anti_alias: false,
             },
             offset: 3.5,
         })
@@ -392,6 +501,10 @@ impl StyleSheet for Default {
             v_offset: -0.75,
         })
     }
+
+    fn tooltip_style(&self) -> Option<tooltip::Style> {
+        Some(tooltip::Style::default())
+    }
 }
 
 impl std::default::Default for Box<dyn StyleSheet> {
@@ -6,11 +6,29 @@ pub mod h_slider;
 pub mod knob;
 pub mod mod_range_input;
 pub mod ramp;
+pub mod segment_display;
 pub mod v_slider;
+pub mod value_marks;
 pub mod xy_pad;
 
+pub mod focus;
 pub mod text_marks;
 pub mod tick_marks;
+pub mod tooltip;
+
+pub mod theme;
+
+/// Multiplies a size in pixels by a scale factor, rounded to the nearest
+/// pixel.
+pub(crate) fn scale_u16(value: u16, scale: f32) -> u16 {
+    (f32::from(value) * scale).round() as u16
+}
+
+/// Multiplies an offset in pixels by a scale factor, rounded to the nearest
+/// pixel.
+pub(crate) fn scale_i16(value: i16, scale: f32) -> i16 {
+    (f32::from(value) * scale).round() as i16
+}
 
 //pub mod db_meter;
 //pub mod phase_meter;
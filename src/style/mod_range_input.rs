@@ -15,6 +15,9 @@ pub enum Style {
     Circle(CircleStyle),
     /// A square style
     Square(SquareStyle),
+    /// A vertical bar style whose fill shows the modulation depth, growing
+    /// up from the center for a positive depth and down for a negative depth
+    VerticalBar(VerticalBarStyle),
     /// Appearance is invisible, but still interactable. Useful if placed right
     /// on top of a [`Knob`] with an [`ModRangeRingStyle`].
     ///
@@ -53,6 +56,35 @@ pub struct SquareStyle {
     pub border_color: Color,
 }
 
+/// A vertical bar [`Style`] for an [`ModRangeInput`]
+///
+/// The center of the bar is zero depth, which is a value of `0.5`.
+///
+/// [`Style`]: enum.Style.html
+/// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+#[derive(Debug, Clone)]
+pub struct VerticalBarStyle {
+    /// The width of the bar. The bar is centered horizontally in the bounds
+    /// of the [`ModRangeInput`].
+    ///
+    /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+    pub width: u16,
+    /// Color of the background of the bar
+    pub back_color: Color,
+    /// Width of the border
+    pub back_border_width: u16,
+    /// Radius of the border
+    pub back_border_radius: u16,
+    /// Color of the border
+    pub back_border_color: Color,
+    /// Color of the fill for a positive depth
+    pub positive_color: Color,
+    /// Color of the fill for a negative depth
+    pub negative_color: Color,
+    /// Color of the line at zero depth. Set this to `None` for no line.
+    pub center_line_color: Option<Color>,
+}
+
 /// A set of rules that dictate the style of a [`ModRangeInput`].
 ///
 /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
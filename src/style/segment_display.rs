@@ -0,0 +1,86 @@
+//! Various styles for the [`SegmentDisplay`] widget
+//!
+//! [`SegmentDisplay`]: ../native/segment_display/struct.SegmentDisplay.html
+
+use iced_native::Color;
+
+use crate::style::default_colors;
+
+/// The appearance of a [`SegmentDisplay`].
+///
+/// [`SegmentDisplay`]: ../../native/segment_display/struct.SegmentDisplay.html
+#[derive(Debug, Clone)]
+pub struct Style {
+    /// The color of the background rectangle
+    pub back_color: Color,
+    /// The width of the border of the background rectangle
+    pub back_border_width: u16,
+    /// The radius of the border of the background rectangle
+    pub back_border_radius: u16,
+    /// The color of the border of the background rectangle
+    pub back_border_color: Color,
+    /// The color of a lit segment
+    pub lit_color: Color,
+    /// The color of an unlit segment. Set this to `None` to not draw unlit
+    /// segments.
+    pub unlit_color: Option<Color>,
+    /// The width of each digit
+    pub digit_width: u16,
+    /// The height of each digit
+    pub digit_height: u16,
+    /// The spacing between each digit. The decimal point is drawn inside this
+    /// spacing.
+    pub digit_spacing: u16,
+    /// The thickness of each segment
+    pub segment_thickness: u16,
+    /// The padding between the digits and the right edge of the background
+    /// rectangle. The digits are right-aligned and vertically centered.
+    pub padding: u16,
+}
+
+/// A set of rules that dictate the style of a [`SegmentDisplay`].
+///
+/// [`SegmentDisplay`]: ../../native/segment_display/struct.SegmentDisplay.html
+pub trait StyleSheet {
+    /// Produces the style of a [`SegmentDisplay`].
+    ///
+    /// [`SegmentDisplay`]: ../../native/segment_display/struct.SegmentDisplay.html
+    fn style(&self) -> Style;
+}
+
+struct Default;
+impl Default {
+    const STYLE: Style = Style {
+        back_color: default_colors::SEGMENT_DISPLAY_BACK,
+        back_border_width: 1,
+        back_border_radius: 2,
+        back_border_color: default_colors::BORDER,
+        lit_color: default_colors::SEGMENT_DISPLAY_LIT,
+        unlit_color: Some(default_colors::SEGMENT_DISPLAY_UNLIT),
+        digit_width: 9,
+        digit_height: 16,
+        digit_spacing: 4,
+        segment_thickness: 2,
+        padding: 4,
+    };
+}
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Self::STYLE
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -4,7 +4,7 @@
 use iced_graphics::{Color, Font};
 
 use crate::core::Offset;
-use crate::style::default_colors;
+use crate::style::{default_colors, scale_u16};
 
 /// The alignment of text in text marks.
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -53,6 +53,34 @@ pub enum Placement {
     },
 }
 
+impl Placement {
+    /// Returns the [`Placement`] with its offset multiplied by `scale`.
+    ///
+    /// [`Placement`]: enum.Placement.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        match self {
+            Placement::BothSides { inside, offset } => Placement::BothSides {
+                inside: *inside,
+                offset: offset.scaled(scale),
+            },
+            Placement::LeftOrTop { inside, offset } => Placement::LeftOrTop {
+                inside: *inside,
+                offset: offset.scaled(scale),
+            },
+            Placement::RightOrBottom { inside, offset } => {
+                Placement::RightOrBottom {
+                    inside: *inside,
+                    offset: offset.scaled(scale),
+                }
+            }
+            Placement::Center { align, offset } => Placement::Center {
+                align: *align,
+                offset: offset.scaled(scale),
+            },
+        }
+    }
+}
+
 impl std::default::Default for Placement {
     fn default() -> Self {
         Placement::LeftOrTop {
@@ -79,6 +107,21 @@ pub struct Style {
     pub bounds_height: u16,
 }
 
+impl Style {
+    /// Returns the [`Style`] with the text size and the size of the text
+    /// bounds multiplied by `scale`, such as for HiDPI displays.
+    ///
+    /// [`Style`]: struct.Style.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        Self {
+            text_size: scale_u16(self.text_size, scale),
+            bounds_width: scale_u16(self.bounds_width, scale),
+            bounds_height: scale_u16(self.bounds_height, scale),
+            ..*self
+        }
+    }
+}
+
 impl std::default::Default for Style {
     fn default() -> Self {
         Self {
@@ -0,0 +1,540 @@
+//! A coordinated set of style sheets for every widget, generated from a
+//! single [`Palette`]
+//!
+//! [`Palette`]: struct.Palette.html
+
+use iced_native::Color;
+
+use crate::core::Offset;
+use crate::style::{
+    default_colors, h_slider, knob, mod_range_input, ramp, segment_display,
+    text_marks, tick_marks, tooltip, v_slider, value_marks, xy_pad,
+};
+
+/// The colors used by a [`Theme`]
+///
+/// [`Theme`]: struct.Theme.html
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Palette {
+    /// The color of the background of widgets and handles
+    pub back: Color,
+    /// The color of the background of hovered widgets and handles
+    pub back_hover: Color,
+    /// The color of the background of widgets and handles that are being
+    /// dragged
+    pub back_drag: Color,
+    /// The color of borders, notches, and lines
+    pub border: Color,
+    /// The color of rails and center lines
+    pub rail: Color,
+    /// The color of the filled (value) portion of widgets
+    pub filled: Color,
+    /// The color of tick marks
+    pub tick: Color,
+    /// The color of text marks and tooltip text
+    pub text: Color,
+}
+
+impl std::default::Default for Palette {
+    fn default() -> Self {
+        Self {
+            back: default_colors::LIGHT_BACK,
+            back_hover: default_colors::LIGHT_BACK_HOVER,
+            back_drag: default_colors::LIGHT_BACK_DRAG,
+            border: default_colors::BORDER,
+            rail: default_colors::XY_PAD_RAIL,
+            filled: default_colors::SLIDER_RAIL.1,
+            tick: default_colors::TICK_TIER_1,
+            text: default_colors::TEXT_MARK,
+        }
+    }
+}
+
+/// Generates a coordinated [`StyleSheet`] for every widget from a single
+/// [`Palette`].
+///
+/// # Example
+///
+/// Pass each style sheet to the `style` method of its widget, such as
+/// `HSlider::new(&mut state, Message::Gain).style(theme.h_slider())`.
+///
+/// ```
+/// use iced_audio::style::theme::{Palette, Theme};
+/// use iced_audio::style::{mod_range_input, tick_marks};
+///
+/// let theme = Theme::new(Palette::default());
+///
+/// let h_slider = theme.h_slider();
+/// let v_slider = theme.v_slider();
+/// let knob = theme.knob();
+/// let xy_pad = theme.xy_pad();
+/// let ramp = theme.ramp();
+/// let mod_range_input = theme.mod_range_input();
+/// let segment_display = theme.segment_display();
+/// let value_marks = theme.value_marks();
+///
+/// // Every style sheet takes its colors from the palette.
+/// match h_slider.tick_marks_style().unwrap().style.tier_1 {
+///     tick_marks::Shape::Line { color, .. } => {
+///         assert_eq!(color, theme.palette.tick)
+///     }
+///     _ => unreachable!(),
+/// }
+/// assert!(v_slider.text_marks_style().is_some());
+/// assert!(knob.value_arc_style().is_some());
+/// assert_eq!(xy_pad.active().back_color, theme.palette.back);
+/// assert_eq!(ramp.hovered().back_color, theme.palette.back_hover);
+/// match mod_range_input.dragging() {
+///     mod_range_input::Style::Circle(style) => {
+///         assert_eq!(style.color, theme.palette.back_drag)
+///     }
+///     _ => unreachable!(),
+/// }
+/// assert_eq!(segment_display.style().lit_color, theme.palette.filled);
+/// assert!(value_marks.tick_marks_style().is_some());
+/// ```
+///
+/// [`StyleSheet`]: ../h_slider/trait.StyleSheet.html
+/// [`Palette`]: struct.Palette.html
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Theme {
+    /// The colors of the theme
+    pub palette: Palette,
+}
+
+impl Theme {
+    /// Creates a new [`Theme`] from a [`Palette`].
+    ///
+    /// [`Theme`]: struct.Theme.html
+    /// [`Palette`]: struct.Palette.html
+    pub fn new(palette: Palette) -> Self {
+        Self { palette }
+    }
+
+    /// Returns the style sheet for an [`HSlider`].
+    ///
+    /// [`HSlider`]: ../../native/h_slider/struct.HSlider.html
+    pub fn h_slider(&self) -> Box<dyn h_slider::StyleSheet> {
+        Box::new(HSliderTheme(self.palette))
+    }
+
+    /// Returns the style sheet for a [`VSlider`].
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    pub fn v_slider(&self) -> Box<dyn v_slider::StyleSheet> {
+        Box::new(VSliderTheme(self.palette))
+    }
+
+    /// Returns the style sheet for a [`Knob`].
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    pub fn knob(&self) -> Box<dyn knob::StyleSheet> {
+        Box::new(KnobTheme(self.palette))
+    }
+
+    /// Returns the style sheet for an [`XYPad`].
+    ///
+    /// [`XYPad`]: ../../native/xy_pad/struct.XYPad.html
+    pub fn xy_pad(&self) -> Box<dyn xy_pad::StyleSheet> {
+        Box::new(XYPadTheme(self.palette))
+    }
+
+    /// Returns the style sheet for a [`Ramp`].
+    ///
+    /// [`Ramp`]: ../../native/ramp/struct.Ramp.html
+    pub fn ramp(&self) -> Box<dyn ramp::StyleSheet> {
+        Box::new(RampTheme(self.palette))
+    }
+
+    /// Returns the style sheet for a [`ModRangeInput`].
+    ///
+    /// [`ModRangeInput`]: ../../native/mod_range_input/struct.ModRangeInput.html
+    pub fn mod_range_input(&self) -> Box<dyn mod_range_input::StyleSheet> {
+        Box::new(ModRangeInputTheme(self.palette))
+    }
+
+    /// Returns the style sheet for a [`SegmentDisplay`].
+    ///
+    /// [`SegmentDisplay`]: ../../native/segment_display/struct.SegmentDisplay.html
+    pub fn segment_display(&self) -> Box<dyn segment_display::StyleSheet> {
+        Box::new(SegmentDisplayTheme(self.palette))
+    }
+
+    /// Returns the style sheet for a [`ValueMarks`].
+    ///
+    /// [`ValueMarks`]: ../../native/value_marks/struct.ValueMarks.html
+    pub fn value_marks(&self) -> Box<dyn value_marks::StyleSheet> {
+        Box::new(ValueMarksTheme(self.palette))
+    }
+}
+
+fn tick_marks_style(palette: &Palette) -> tick_marks::Style {
+    tick_marks::Style {
+        tier_1: tick_marks::Shape::Line {
+            length: 24,
+            width: 2,
+            color: palette.tick,
+        },
+        tier_2: tick_marks::Shape::Line {
+            length: 22,
+            width: 1,
+            color: Color {
+                a: palette.tick.a * 0.9,
+                ..palette.tick
+            },
+        },
+        tier_3: tick_marks::Shape::Line {
+            length: 18,
+            width: 1,
+            color: Color {
+                a: palette.tick.a * 0.7,
+                ..palette.tick
+            },
+        },
+        tier_offsets: Default::default(),
+        anti_alias: false,
+    }
+}
+
+fn text_marks_style(palette: &Palette) -> text_marks::Style {
+    text_marks::Style {
+        color: palette.text,
+        ..text_marks::Style::default()
+    }
+}
+
+fn tooltip_style(palette: &Palette) -> tooltip::Style {
+    tooltip::Style {
+        back_color: palette.back,
+        border_color: palette.border,
+        text_color: palette.text,
+        ..tooltip::Style::default()
+    }
+}
+
+struct HSliderTheme(Palette);
+impl HSliderTheme {
+    fn rect_style(&self, back_color: Color) -> h_slider::Style {
+        h_slider::Style::Rect(h_slider::RectStyle {
+            back_color,
+            back_border_width: 1,
+            back_border_radius: 2,
+            back_border_color: self.0.border,
+            filled_color: self.0.filled,
+            handle_color: self.0.border,
+            handle_width: 4,
+            handle_filled_gap: 1,
+        })
+    }
+}
+impl h_slider::StyleSheet for HSliderTheme {
+    fn active(&self) -> h_slider::Style {
+        self.rect_style(self.0.back)
+    }
+
+    fn hovered(&self) -> h_slider::Style {
+        self.rect_style(self.0.back_hover)
+    }
+
+    fn dragging(&self) -> h_slider::Style {
+        self.rect_style(self.0.back_drag)
+    }
+
+    fn tick_marks_style(&self) -> Option<h_slider::TickMarksStyle> {
+        Some(h_slider::TickMarksStyle {
+            style: tick_marks_style(&self.0),
+            placement: tick_marks::Placement::Center {
+                offset: Offset::ZERO,
+                fill_length: false,
+            },
+        })
+    }
+
+    fn text_marks_style(&self) -> Option<h_slider::TextMarksStyle> {
+        Some(h_slider::TextMarksStyle {
+            style: text_marks_style(&self.0),
+            placement: text_marks::Placement::RightOrBottom {
+                inside: false,
+                offset: Offset { x: 0, y: 7 },
+            },
+        })
+    }
+
+    fn tooltip_style(&self) -> Option<tooltip::Style> {
+        Some(tooltip_style(&self.0))
+    }
+}
+
+struct VSliderTheme(Palette);
+impl VSliderTheme {
+    fn rect_style(&self, back_color: Color) -> v_slider::Style {
+        v_slider::Style::Rect(v_slider::RectStyle {
+            back_color,
+            back_border_width: 1,
+            back_border_radius: 2,
+            back_border_color: self.0.border,
+            filled_color: self.0.filled,
+            handle_color: self.0.border,
+            handle_height: 4,
+            handle_filled_gap: 1,
+        })
+    }
+}
+impl v_slider::StyleSheet for VSliderTheme {
+    fn active(&self) -> v_slider::Style {
+        self.rect_style(self.0.back)
+    }
+
+    fn hovered(&self) -> v_slider::Style {
+        self.rect_style(self.0.back_hover)
+    }
+
+    fn dragging(&self) -> v_slider::Style {
+        self.rect_style(self.0.back_drag)
+    }
+
+    fn tick_marks_style(&self) -> Option<v_slider::TickMarksStyle> {
+        Some(v_slider::TickMarksStyle {
+            style: tick_marks_style(&self.0),
+            placement: tick_marks::Placement::Center {
+                offset: Offset::ZERO,
+                fill_length: false,
+            },
+        })
+    }
+
+    fn text_marks_style(&self) -> Option<v_slider::TextMarksStyle> {
+        Some(v_slider::TextMarksStyle {
+            style: text_marks_style(&self.0),
+            placement: text_marks::Placement::LeftOrTop {
+                inside: false,
+                offset: Offset { x: -7, y: 0 },
+            },
+        })
+    }
+
+    fn tooltip_style(&self) -> Option<tooltip::Style> {
+        Some(tooltip_style(&self.0))
+    }
+}
+
+struct KnobTheme(Palette);
+impl KnobTheme {
+    fn circle_style(&self, color: Color) -> knob::Style {
+        knob::Style::Circle(knob::CircleStyle {
+            color,
+            border_width: 1,
+            border_color: self.0.border,
+            notch: knob::NotchShape::Circle(knob::CircleNotch {
+                color: self.0.border,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+                diameter: knob::StyleLength::Scaled(0.17),
+                offset: knob::StyleLength::Scaled(0.15),
+            }),
+            rings: Vec::new(),
+        })
+    }
+}
+impl knob::StyleSheet for KnobTheme {
+    fn active(&self) -> knob::Style {
+        self.circle_style(self.0.back)
+    }
+
+    fn hovered(&self) -> knob::Style {
+        self.circle_style(self.0.back_hover)
+    }
+
+    fn dragging(&self) -> knob::Style {
+        self.circle_style(self.0.back_drag)
+    }
+
+    fn tick_marks_style(&self) -> Option<knob::TickMarksStyle> {
+        Some(knob::TickMarksStyle {
+            style: tick_marks::Style {
+                tier_1: tick_marks::Shape::Circle {
+                    diameter: 4,
+                    color: self.0.tick,
+                },
+                tier_2: tick_marks::Shape::Circle {
+                    diameter: 2,
+                    color: self.0.tick,
+                },
+                tier_3: tick_marks::Shape::Circle {
+                    diameter: 2,
+                    color: Color {
+                        a: self.0.tick.a * 0.7,
+                        ..self.0.tick
+                    },
+                },
+                tier_offsets: Default::default(),
+                anti_alias: false,
+            },
+            offset: 3.5,
+        })
+    }
+
+    fn value_arc_style(&self) -> Option<knob::ValueArcStyle> {
+        Some(knob::ValueArcStyle {
+            width: 2.0,
+            offset: 1.5,
+            empty_color: None,
+            left_filled_color: self.0.filled,
+            right_filled_color: None,
+            fill_mode: knob::ArcFillMode::FromStart,
+            cap: knob::LineCap::Square,
+        })
+    }
+
+    fn text_marks_style(&self) -> Option<knob::TextMarksStyle> {
+        Some(knob::TextMarksStyle {
+            style: text_marks_style(&self.0),
+            offset: 14.0,
+            h_char_offset: 3.0,
+            v_offset: -0.75,
+        })
+    }
+
+    fn tooltip_style(&self) -> Option<tooltip::Style> {
+        Some(tooltip_style(&self.0))
+    }
+}
+
+struct XYPadTheme(Palette);
+impl XYPadTheme {
+    fn style(
+        &self,
+        handle_color: Color,
+        handle_diameter: u16,
+    ) -> xy_pad::Style {
+        xy_pad::Style {
+            rail_width: 2,
+            h_rail_color: self.0.rail,
+            v_rail_color: self.0.rail,
+            handle: xy_pad::HandleShape::Circle(xy_pad::HandleCircle {
+                color: handle_color,
+                diameter: handle_diameter,
+                border_width: 2,
+                border_color: self.0.border,
+            }),
+            back_color: self.0.back,
+            border_width: 1,
+            border_color: self.0.border,
+            center_line_width: 1,
+            center_line_color: Color {
+                a: self.0.rail.a * 0.5,
+                ..self.0.rail
+            },
+        }
+    }
+}
+impl xy_pad::StyleSheet for XYPadTheme {
+    fn active(&self) -> xy_pad::Style {
+        self.style(self.0.back, 11)
+    }
+
+    fn hovered(&self) -> xy_pad::Style {
+        self.style(self.0.back_hover, 11)
+    }
+
+    fn dragging(&self) -> xy_pad::Style {
+        self.style(self.0.back_drag, 9)
+    }
+}
+
+struct RampTheme(Palette);
+impl RampTheme {
+    fn style(&self, back_color: Color) -> ramp::Style {
+        ramp::Style {
+            back_color,
+            back_border_width: 1,
+            back_border_color: self.0.border,
+            line_width: 2.0,
+            line_center_color: self.0.border,
+            line_up_color: self.0.filled,
+            line_down_color: self.0.filled,
+        }
+    }
+}
+impl ramp::StyleSheet for RampTheme {
+    fn active(&self) -> ramp::Style {
+        self.style(self.0.back)
+    }
+
+    fn hovered(&self) -> ramp::Style {
+        self.style(self.0.back_hover)
+    }
+
+    fn dragging(&self) -> ramp::Style {
+        self.style(self.0.back_drag)
+    }
+}
+
+struct ModRangeInputTheme(Palette);
+impl ModRangeInputTheme {
+    fn style(&self, color: Color) -> mod_range_input::Style {
+        mod_range_input::Style::Circle(mod_range_input::CircleStyle {
+            color,
+            border_width: 1,
+            border_color: self.0.border,
+        })
+    }
+}
+impl mod_range_input::StyleSheet for ModRangeInputTheme {
+    fn active(&self) -> mod_range_input::Style {
+        self.style(self.0.back)
+    }
+
+    fn hovered(&self) -> mod_range_input::Style {
+        self.style(self.0.back_hover)
+    }
+
+    fn dragging(&self) -> mod_range_input::Style {
+        self.style(self.0.back_drag)
+    }
+}
+
+struct SegmentDisplayTheme(Palette);
+impl segment_display::StyleSheet for SegmentDisplayTheme {
+    fn style(&self) -> segment_display::Style {
+        segment_display::Style {
+            back_color: self.0.back,
+            back_border_width: 1,
+            back_border_radius: 2,
+            back_border_color: self.0.border,
+            lit_color: self.0.filled,
+            unlit_color: Some(Color {
+                a: self.0.filled.a * 0.1,
+                ..self.0.filled
+            }),
+            digit_width: 9,
+            digit_height: 16,
+            digit_spacing: 4,
+            segment_thickness: 2,
+            padding: 4,
+        }
+    }
+}
+
+struct ValueMarksTheme(Palette);
+impl value_marks::StyleSheet for ValueMarksTheme {
+    fn tick_marks_style(&self) -> Option<value_marks::TickMarksStyle> {
+        Some(value_marks::TickMarksStyle {
+            style: tick_marks_style(&self.0),
+            placement: tick_marks::Placement::BothSides {
+                offset: Offset::ZERO,
+                inside: true,
+            },
+        })
+    }
+
+    fn text_marks_style(&self) -> Option<value_marks::TextMarksStyle> {
+        Some(value_marks::TextMarksStyle {
+            style: text_marks_style(&self.0),
+            placement: text_marks::Placement::Center {
+                align: text_marks::Align::Center,
+                offset: Offset::ZERO,
+            },
+        })
+    }
+}
@@ -4,7 +4,7 @@
 use iced_native::Color;
 
 use crate::core::Offset;
-use crate::style::default_colors;
+use crate::style::{default_colors, scale_i16, scale_u16};
 
 /// The placement of tick marks relative to the widget
 #[derive(Debug, Clone, PartialEq)]
@@ -56,6 +56,47 @@ pub enum Placement {
     },
 }
 
+impl Placement {
+    /// Returns the [`Placement`] with all of its offsets and sizes
+    /// multiplied by `scale`.
+    ///
+    /// [`Placement`]: enum.Placement.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        match self {
+            Placement::BothSides { offset, inside } => Placement::BothSides {
+                offset: offset.scaled(scale),
+                inside: *inside,
+            },
+            Placement::LeftOrTop { offset, inside } => Placement::LeftOrTop {
+                offset: offset.scaled(scale),
+                inside: *inside,
+            },
+            Placement::RightOrBottom { offset, inside } => {
+                Placement::RightOrBottom {
+                    offset: offset.scaled(scale),
+                    inside: *inside,
+                }
+            }
+            Placement::Center {
+                offset,
+                fill_length,
+            } => Placement::Center {
+                offset: offset.scaled(scale),
+                fill_length: *fill_length,
+            },
+            Placement::CenterSplit {
+                offset,
+                fill_length,
+                gap,
+            } => Placement::CenterSplit {
+                offset: offset.scaled(scale),
+                fill_length: *fill_length,
+                gap: scale_u16(*gap, scale),
+            },
+        }
+    }
+}
+
 impl std::default::Default for Placement {
     fn default() -> Self {
         Placement::BothSides {
@@ -74,6 +115,66 @@ pub struct Style {
     pub tier_2: Shape,
     /// The style of a tier 3 tick mark.
     pub tier_3: Shape,
+    /// The additional offset of each tier from the edge of the widget.
+    pub tier_offsets: TierOffsets,
+    /// Whether to render the tick marks as anti-aliased canvas paths (true)
+    /// or as pixel-aligned quads (false). Anti-aliased tick marks keep their
+    /// sub-pixel positions instead of being snapped to whole pixels.
+    ///
+    /// Tick marks around a [`Knob`] are always anti-aliased.
+    ///
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    pub anti_alias: bool,
+}
+
+impl Style {
+    /// Returns the [`Style`] with the sizes and offsets of every tier
+    /// multiplied by `scale`, such as for HiDPI displays.
+    ///
+    /// [`Style`]: struct.Style.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        Self {
+            tier_1: self.tier_1.scaled(scale),
+            tier_2: self.tier_2.scaled(scale),
+            tier_3: self.tier_3.scaled(scale),
+            tier_offsets: self.tier_offsets.scaled(scale),
+            anti_alias: self.anti_alias,
+        }
+    }
+}
+
+/// The additional offset in pixels of each tier of tick marks from the edge
+/// of the widget, on top of the offset of the [`Placement`].
+///
+/// Positive values move the tick marks farther from the edge in the
+/// direction the tick marks extend. For [`Placement::CenterSplit`], the
+/// offset widens the gap between the split tick marks. The offsets have no
+/// effect for [`Placement::Center`].
+///
+/// [`Placement`]: enum.Placement.html
+/// [`Placement::CenterSplit`]: enum.Placement.html#variant.CenterSplit
+/// [`Placement::Center`]: enum.Placement.html#variant.Center
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct TierOffsets {
+    /// The offset of tier 1 tick marks in pixels.
+    pub tier_1: i16,
+    /// The offset of tier 2 tick marks in pixels.
+    pub tier_2: i16,
+    /// The offset of tier 3 tick marks in pixels.
+    pub tier_3: i16,
+}
+
+impl TierOffsets {
+    /// Returns the [`TierOffsets`] with every offset multiplied by `scale`.
+    ///
+    /// [`TierOffsets`]: struct.TierOffsets.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        Self {
+            tier_1: scale_i16(self.tier_1, scale),
+            tier_2: scale_i16(self.tier_2, scale),
+            tier_3: scale_i16(self.tier_3, scale),
+        }
+    }
 }
 
 /// The shape of a tick mark
@@ -92,6 +193,37 @@ pub enum Shape {
         /// The color of the tick mark.
         color: Color,
     },
+    /// Dashed line shape. For a dotted line, set `dash` to the same value
+    /// as `width`.
+    DashedLine {
+        /// The length of the tick mark.
+        length: u16,
+
+        /// The width (thickness) of the tick mark.
+        width: u16,
+
+        /// The length of each dash.
+        dash: u16,
+
+        /// The length of the gap between each dash.
+        gap: u16,
+
+        /// The color of the tick mark.
+        color: Color,
+    },
+    /// Bar shape that spans the full width of the bounds (or the full
+    /// height for horizontal tick marks), regardless of the side of the
+    /// [`Placement`]. This has no effect on tick marks around a [`Knob`].
+    ///
+    /// [`Placement`]: enum.Placement.html
+    /// [`Knob`]: ../../native/knob/struct.Knob.html
+    Bar {
+        /// The thickness of the bar.
+        thickness: u16,
+
+        /// The color of the bar.
+        color: Color,
+    },
     /// Circle shape
     Circle {
         /// The diameter of the tick mark.
@@ -102,6 +234,56 @@ pub enum Shape {
     },
 }
 
+impl Shape {
+    /// Returns the [`Shape`] with all of its sizes multiplied by `scale`.
+    /// Sizes that are not `0` stay at least `1` pixel.
+    ///
+    /// [`Shape`]: enum.Shape.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        let size = |value: u16| {
+            if value == 0 {
+                0
+            } else {
+                scale_u16(value, scale).max(1)
+            }
+        };
+
+        match self {
+            Shape::None => Shape::None,
+            Shape::Line {
+                length,
+                width,
+                color,
+            } => Shape::Line {
+                length: size(*length),
+                width: size(*width),
+                color: *color,
+            },
+            Shape::DashedLine {
+                length,
+                width,
+                dash,
+                gap,
+                color,
+            } => Shape::DashedLine {
+                length: size(*length),
+                width: size(*width),
+                dash: size(*dash),
+                gap: size(*gap),
+                color: *color,
+            },
+            Shape::Bar { thickness, color } => Shape::Bar {
+                thickness: size(*thickness),
+                color: *color,
+            },
+            Shape::Circle { diameter, color } => Shape::Circle {
+                diameter: size(*diameter),
+                color: *color,
+            },
+        }
+    }
+}
+
 impl std::default::Default for Style {
     fn default() -> Self {
         Self {
@@ -120,6 +302,8 @@ impl std::default::Default for Style {
                 width: 1,
                 color: default_colors::TICK_TIER_3,
             },
+            tier_offsets: Default::default(),
+            anti_alias: false,
         }
     }
 }
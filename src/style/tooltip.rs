@@ -0,0 +1,76 @@
+//! Various styles for a value tooltip
+use iced_graphics::{Color, Font};
+
+use crate::core::Offset;
+use crate::style::{default_colors, scale_u16};
+
+/// The style of a tooltip that displays the value of a widget
+#[derive(Debug, Copy, Clone)]
+pub struct Style {
+    /// The color of the background.
+    pub back_color: Color,
+    /// The width of the background border.
+    pub border_width: u16,
+    /// The radius of the background border.
+    pub border_radius: u16,
+    /// The color of the background border.
+    pub border_color: Color,
+    /// The color of the text.
+    pub text_color: Color,
+    /// The size of the text.
+    pub text_size: u16,
+    /// The font of the text.
+    pub font: Font,
+    /// The smallest width of the tooltip. The tooltip grows wider to fit
+    /// longer text.
+    pub min_width: u16,
+    /// The height of the tooltip.
+    pub height: u16,
+    /// The horizontal padding between the text and each side of the tooltip.
+    pub padding: u16,
+    /// The approximate width of a character relative to the size of the text.
+    /// This is used to size the tooltip to fit its text.
+    ///
+    /// The default is `0.6`.
+    pub char_width: f32,
+    /// The offset of the bottom-left corner of the tooltip from the cursor.
+    pub offset: Offset,
+}
+
+impl Style {
+    /// Returns the [`Style`] with all of its sizes multiplied by `scale`,
+    /// such as for HiDPI displays. Colors and the font are unchanged.
+    ///
+    /// [`Style`]: struct.Style.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        Self {
+            border_width: scale_u16(self.border_width, scale),
+            border_radius: scale_u16(self.border_radius, scale),
+            text_size: scale_u16(self.text_size, scale),
+            min_width: scale_u16(self.min_width, scale),
+            height: scale_u16(self.height, scale),
+            padding: scale_u16(self.padding, scale),
+            offset: self.offset.scaled(scale),
+            ..*self
+        }
+    }
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            back_color: default_colors::LIGHT_BACK,
+            border_width: 1,
+            border_radius: 2,
+            border_color: default_colors::BORDER,
+            text_color: default_colors::TEXT_MARK,
+            text_size: 12,
+            font: Default::default(),
+            min_width: 40,
+            height: 18,
+            padding: 5,
+            char_width: 0.6,
+            offset: Offset { x: 10, y: -6 },
+        }
+    }
+}
@@ -5,7 +5,10 @@
 use iced_native::{image, Color, Rectangle};
 
 use crate::core::Offset;
-use crate::style::{default_colors, text_marks, tick_marks};
+use crate::style::{
+    default_colors, focus, scale_i16, scale_u16, text_marks, tick_marks,
+    tooltip,
+};
 
 /// The appearance of a [`VSlider`].
 ///
@@ -21,6 +24,45 @@ pub enum Style {
     /// same as `Rect` but can have different colors for left,
     /// right, and center positions
     RectBipolar(RectBipolarStyle),
+    /// a column of discrete segments that light up like an LED meter
+    Segmented(SegmentedStyle),
+}
+
+impl Style {
+    /// Returns the [`Style`] with all of its sizes multiplied by `scale`,
+    /// such as for HiDPI displays. Colors and textures are unchanged.
+    ///
+    /// [`Style`]: enum.Style.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        match self {
+            Style::Texture(style) => Style::Texture(style.scaled(scale)),
+            Style::Classic(style) => Style::Classic(style.scaled(scale)),
+            Style::Rect(style) => Style::Rect(style.scaled(scale)),
+            Style::RectBipolar(style) => {
+                Style::RectBipolar(style.scaled(scale))
+            }
+            Style::Segmented(style) => Style::Segmented(style.scaled(scale)),
+        }
+    }
+
+    /// Returns the height in pixels of the handle as it is drawn by this
+    /// [`Style`], including its border. The segmented style has no handle
+    /// and returns `0`.
+    ///
+    /// [`Style`]: enum.Style.html
+    pub fn handle_height(&self) -> u16 {
+        match self {
+            Style::Texture(style) => style.handle_height,
+            Style::Classic(style) => style.handle.height,
+            Style::Rect(style) => {
+                style.handle_height + (style.back_border_width * 2)
+            }
+            Style::RectBipolar(style) => {
+                style.handle_height + (style.back_border_width * 2)
+            }
+            Style::Segmented(_) => 0,
+        }
+    }
 }
 
 /// A classic line rail style
@@ -34,6 +76,23 @@ pub struct ClassicRail {
     pub rail_padding: u16,
 }
 
+impl ClassicRail {
+    /// Returns the [`ClassicRail`] with its widths and padding multiplied by
+    /// `scale`.
+    ///
+    /// [`ClassicRail`]: struct.ClassicRail.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        Self {
+            rail_colors: self.rail_colors,
+            rail_widths: (
+                scale_u16(self.rail_widths.0, scale),
+                scale_u16(self.rail_widths.1, scale),
+            ),
+            rail_padding: scale_u16(self.rail_padding, scale),
+        }
+    }
+}
+
 /// A [`Style`] for a [`VSlider`] that uses an image texture for the handle
 ///
 /// [`Style`]: enum.Style.html
@@ -52,6 +111,26 @@ pub struct TextureStyle {
     pub image_bounds: Rectangle,
 }
 
+impl TextureStyle {
+    /// Returns the [`TextureStyle`] with all of its sizes multiplied by
+    /// `scale`. The image texture itself is stretched to the scaled bounds.
+    ///
+    /// [`TextureStyle`]: struct.TextureStyle.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        Self {
+            rail: self.rail.scaled(scale),
+            image_handle: self.image_handle.clone(),
+            handle_height: scale_u16(self.handle_height, scale),
+            image_bounds: Rectangle {
+                x: self.image_bounds.x * scale,
+                y: self.image_bounds.y * scale,
+                width: self.image_bounds.width * scale,
+                height: self.image_bounds.height * scale,
+            },
+        }
+    }
+}
+
 /// A classic [`Style`] for a [`VSlider`], modeled after hardware sliders
 ///
 /// [`Style`]: enum.Style.html
@@ -65,6 +144,19 @@ pub struct ClassicStyle {
     pub handle: ClassicHandle,
 }
 
+impl ClassicStyle {
+    /// Returns the [`ClassicStyle`] with all of its sizes multiplied by
+    /// `scale`.
+    ///
+    /// [`ClassicStyle`]: struct.ClassicStyle.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        Self {
+            rail: self.rail.scaled(scale),
+            handle: self.handle.scaled(scale),
+        }
+    }
+}
+
 /// The [`ClassicStyle`] appearance of the handle of a [`VSlider`]
 ///
 /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
@@ -87,6 +179,22 @@ pub struct ClassicHandle {
     pub border_color: Color,
 }
 
+impl ClassicHandle {
+    /// Returns the [`ClassicHandle`] with all of its sizes multiplied by
+    /// `scale`.
+    ///
+    /// [`ClassicHandle`]: struct.ClassicHandle.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        Self {
+            height: scale_u16(self.height, scale),
+            notch_width: scale_u16(self.notch_width, scale),
+            border_radius: scale_u16(self.border_radius, scale),
+            border_width: scale_u16(self.border_width, scale),
+            ..*self
+        }
+    }
+}
+
 /// A modern [`Style`] for a [`VSlider`]. It is composed of a background
 /// rectangle and a rectangular handle.
 ///
@@ -113,6 +221,21 @@ pub struct RectStyle {
     pub handle_filled_gap: u16,
 }
 
+impl RectStyle {
+    /// Returns the [`RectStyle`] with all of its sizes multiplied by `scale`.
+    ///
+    /// [`RectStyle`]: struct.RectStyle.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        Self {
+            back_border_width: scale_u16(self.back_border_width, scale),
+            back_border_radius: scale_u16(self.back_border_radius, scale),
+            handle_height: scale_u16(self.handle_height, scale),
+            handle_filled_gap: scale_u16(self.handle_filled_gap, scale),
+            ..*self
+        }
+    }
+}
+
 /// A modern [`Style`] for a [`VSlider`]. It is composed of a background
 /// rectangle and a rectangular handle. It has different colors for left, right,
 /// and center values.
@@ -150,6 +273,65 @@ pub struct RectBipolarStyle {
     pub handle_filled_gap: u16,
 }
 
+impl RectBipolarStyle {
+    /// Returns the [`RectBipolarStyle`] with all of its sizes multiplied by
+    /// `scale`.
+    ///
+    /// [`RectBipolarStyle`]: struct.RectBipolarStyle.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        Self {
+            back_border_width: scale_u16(self.back_border_width, scale),
+            back_border_radius: scale_u16(self.back_border_radius, scale),
+            handle_height: scale_u16(self.handle_height, scale),
+            handle_filled_gap: scale_u16(self.handle_filled_gap, scale),
+            ..*self
+        }
+    }
+}
+
+/// A [`Style`] for a [`VSlider`] that displays the value as a column of
+/// discrete segments that light up like an LED meter. It has no handle.
+///
+/// [`Style`]: enum.Style.html
+/// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+#[derive(Debug, Clone, Copy)]
+pub struct SegmentedStyle {
+    /// color of the background rectangle
+    pub back_color: Color,
+    /// width of the background rectangle border
+    pub back_border_width: u16,
+    /// radius of the background rectangle
+    pub back_border_radius: u16,
+    /// color of the background rectangle border
+    pub back_border_color: Color,
+    /// the number of segments
+    pub segment_count: u16,
+    /// the gap between each segment
+    pub segment_gap: u16,
+    /// radius of each segment
+    pub segment_border_radius: u16,
+    /// color of a lit segment
+    pub lit_color: Color,
+    /// color of an unlit segment
+    pub unlit_color: Color,
+}
+
+impl SegmentedStyle {
+    /// Returns the [`SegmentedStyle`] with all of its sizes multiplied by
+    /// `scale`. The number of segments is unchanged.
+    ///
+    /// [`SegmentedStyle`]: struct.SegmentedStyle.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        Self {
+            back_border_width: scale_u16(self.back_border_width, scale),
+            back_border_radius: scale_u16(self.back_border_radius, scale),
+            segment_gap: scale_u16(self.segment_gap, scale),
+            segment_border_radius: scale_u16(self.segment_border_radius, scale),
+            ..*self
+        }
+    }
+}
+
 /// The position of a [`ModRangeStyle`] ring for a [`VSlider`]
 ///
 /// [`ModRangeStyle`]: struct.ModRangeStyle.html
@@ -185,6 +367,40 @@ pub enum ModRangePlacement {
     },
 }
 
+impl ModRangePlacement {
+    /// Returns the [`ModRangePlacement`] with its sizes and offsets
+    /// multiplied by `scale`.
+    ///
+    /// [`ModRangePlacement`]: enum.ModRangePlacement.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        match self {
+            ModRangePlacement::Center { width, offset } => {
+                ModRangePlacement::Center {
+                    width: scale_u16(*width, scale),
+                    offset: scale_i16(*offset, scale),
+                }
+            }
+            ModRangePlacement::CenterFilled { edge_padding } => {
+                ModRangePlacement::CenterFilled {
+                    edge_padding: scale_u16(*edge_padding, scale),
+                }
+            }
+            ModRangePlacement::Left { width, offset } => {
+                ModRangePlacement::Left {
+                    width: scale_u16(*width, scale),
+                    offset: scale_i16(*offset, scale),
+                }
+            }
+            ModRangePlacement::Right { width, offset } => {
+                ModRangePlacement::Right {
+                    width: scale_u16(*width, scale),
+                    offset: scale_i16(*offset, scale),
+                }
+            }
+        }
+    }
+}
+
 /// A style for a [`ModulationRange`] line for a [`VSlider`]
 ///
 /// [`ModulationRange`]: ../../core/struct.ModulationRange.html
@@ -209,6 +425,21 @@ pub struct ModRangeStyle {
     pub filled_inverse_color: Color,
 }
 
+impl ModRangeStyle {
+    /// Returns the [`ModRangeStyle`] with all of its sizes multiplied by
+    /// `scale`.
+    ///
+    /// [`ModRangeStyle`]: struct.ModRangeStyle.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        Self {
+            placement: self.placement.scaled(scale),
+            back_border_width: scale_u16(self.back_border_width, scale),
+            back_border_radius: scale_u16(self.back_border_radius, scale),
+            ..self.clone()
+        }
+    }
+}
+
 /// Style of tick marks for a [`VSlider`].
 ///
 /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
@@ -220,6 +451,19 @@ pub struct TickMarksStyle {
     pub placement: tick_marks::Placement,
 }
 
+impl TickMarksStyle {
+    /// Returns the [`TickMarksStyle`] with all of its sizes and offsets
+    /// multiplied by `scale`.
+    ///
+    /// [`TickMarksStyle`]: struct.TickMarksStyle.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        Self {
+            style: self.style.scaled(scale),
+            placement: self.placement.scaled(scale),
+        }
+    }
+}
+
 /// Style of text marks for a [`VSlider`].
 ///
 /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
@@ -231,6 +475,19 @@ pub struct TextMarksStyle {
     pub placement: text_marks::Placement,
 }
 
+impl TextMarksStyle {
+    /// Returns the [`TextMarksStyle`] with all of its sizes and offsets
+    /// multiplied by `scale`.
+    ///
+    /// [`TextMarksStyle`]: struct.TextMarksStyle.html
+    pub fn scaled(&self, scale: f32) -> Self {
+        Self {
+            style: self.style.scaled(scale),
+            placement: self.placement.scaled(scale),
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a [`VSlider`].
 ///
 /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
@@ -287,6 +544,25 @@ pub trait StyleSheet {
     fn text_marks_style(&self) -> Option<TextMarksStyle> {
         None
     }
+
+    /// The style of the value tooltip for a [`VSlider`]
+    ///
+    /// For no tooltip, don't override this or set this to return `None`.
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    fn tooltip_style(&self) -> Option<tooltip::Style> {
+        None
+    }
+
+    /// The style of the outline drawn around a [`VSlider`] that has keyboard
+    /// focus
+    ///
+    /// For no focus outline, don't override this or set this to return `None`.
+    ///
+    /// [`VSlider`]: ../../native/v_slider/struct.VSlider.html
+    fn focus_style(&self) -> Option<focus::Style> {
+        None
+    }
 }
 
 struct Default;
@@ -351,6 +627,8 @@ impl StyleSheet for Default {
                     width: 1,
                     color: default_colors::TICK_TIER_3,
                 },
+                tier_offsets: tick_marks::TierOffsets::default(),
+                anti_alias: false,
             },
             placement: tick_marks::Placement::Center {
                 offset: Offset::ZERO,
@@ -368,6 +646,10 @@ impl StyleSheet for Default {
             },
         })
     }
+
+    fn tooltip_style(&self) -> Option<tooltip::Style> {
+        Some(tooltip::Style::default())
+    }
 }
 
 impl std::default::Default for Box<dyn StyleSheet> {
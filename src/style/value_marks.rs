@@ -0,0 +1,85 @@
+//! Various styles for the [`ValueMarks`] widget
+//!
+//! [`ValueMarks`]: ../native/value_marks/struct.ValueMarks.html
+
+use crate::core::Offset;
+use crate::style::{text_marks, tick_marks};
+
+/// Style of the tick marks of a [`ValueMarks`].
+///
+/// [`ValueMarks`]: ../../native/value_marks/struct.ValueMarks.html
+#[derive(Debug, Clone)]
+pub struct TickMarksStyle {
+    /// The style of the tick marks
+    pub style: tick_marks::Style,
+    /// The placement of the tick marks
+    pub placement: tick_marks::Placement,
+}
+
+/// Style of the text marks of a [`ValueMarks`].
+///
+/// [`ValueMarks`]: ../../native/value_marks/struct.ValueMarks.html
+#[derive(Debug, Clone)]
+pub struct TextMarksStyle {
+    /// The style of the text marks
+    pub style: text_marks::Style,
+    /// The placement of the text marks
+    pub placement: text_marks::Placement,
+}
+
+/// A set of rules that dictate the style of a [`ValueMarks`].
+///
+/// [`ValueMarks`]: ../../native/value_marks/struct.ValueMarks.html
+pub trait StyleSheet {
+    /// The style of the tick marks of a [`ValueMarks`]
+    ///
+    /// For no tick marks, set this to return `None`.
+    ///
+    /// [`ValueMarks`]: ../../native/value_marks/struct.ValueMarks.html
+    fn tick_marks_style(&self) -> Option<TickMarksStyle>;
+
+    /// The style of the text marks of a [`ValueMarks`]
+    ///
+    /// For no text marks, set this to return `None`.
+    ///
+    /// [`ValueMarks`]: ../../native/value_marks/struct.ValueMarks.html
+    fn text_marks_style(&self) -> Option<TextMarksStyle>;
+}
+
+struct Default;
+impl StyleSheet for Default {
+    fn tick_marks_style(&self) -> Option<TickMarksStyle> {
+        Some(TickMarksStyle {
+            style: tick_marks::Style::default(),
+            placement: tick_marks::Placement::BothSides {
+                offset: Offset::ZERO,
+                inside: true,
+            },
+        })
+    }
+
+    fn text_marks_style(&self) -> Option<TextMarksStyle> {
+        Some(TextMarksStyle {
+            style: text_marks::Style::default(),
+            placement: text_marks::Placement::Center {
+                align: text_marks::Align::Center,
+                offset: Offset::ZERO,
+            },
+        })
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}